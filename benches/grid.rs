@@ -0,0 +1,147 @@
+//! Compares `Grid`'s flat storage with the nested `Vec<Vec<T>>` it replaced, on a large grid.
+//!
+//! Run with `cargo bench --bench grid`.  Each case reports the median of several runs.  Under
+//! `cargo test` it only checks the two layouts agree, on a small grid.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc::grid::Grid;
+
+/// The grid's width and height, and how many times each case runs, when benchmarking.
+const SIZE: usize = 2000;
+const RUNS: usize = 15;
+
+/// The old layout, with its `get` and copying `rows()`/`cols()`.
+struct Nested {
+    cells: Vec<Vec<u8>>,
+}
+
+impl Nested {
+    fn get(&self, x: usize, y: usize) -> Option<u8> {
+        self.cells.get(y).and_then(|row| row.get(x)).copied()
+    }
+
+    fn rows(&self) -> Vec<Vec<u8>> {
+        self.cells.clone()
+    }
+
+    fn cols(&self) -> Vec<Vec<u8>> {
+        (0..self.cells[0].len())
+            .map(|x| (0..self.cells.len()).map(|y| self.cells[y][x]).collect())
+            .collect()
+    }
+}
+
+fn main() {
+    // cargo bench passes --bench, cargo test doesn't
+    let (size, runs) = match std::env::args().any(|arg| arg == "--bench") {
+        true => (SIZE, RUNS),
+        false => (20, 1),
+    };
+
+    let rows: Vec<Vec<u8>> = (0..size)
+        .map(|y| (0..size).map(|x| ((x * 7 + y * 13) % 251) as u8).collect())
+        .collect();
+    let nested = Nested {
+        cells: rows.clone(),
+    };
+    let flat = Grid::new(rows);
+
+    match runs {
+        1 => println!("{size}x{size} grid, 1 run\n"),
+        runs => println!("{size}x{size} grid, median of {runs} runs\n"),
+    }
+    println!("{:<22} {:>12} {:>12}", "", "nested", "flat");
+
+    compare(
+        runs,
+        "get, row by row",
+        || sum_by(size, |x, y| nested.get(x, y)),
+        || sum_by(size, |x, y| flat.get(x, y)),
+    );
+    compare(
+        runs,
+        "get, column by column",
+        || sum_by(size, |y, x| nested.get(x, y)),
+        || sum_by(size, |y, x| flat.get(x, y)),
+    );
+    compare(
+        runs,
+        "8 neighbours each",
+        || neighbours(size, |x, y| nested.get(x, y)),
+        || neighbours(size, |x, y| flat.get(x, y)),
+    );
+    compare(
+        runs,
+        "rows()",
+        || nested.rows().iter().flatten().map(|&c| c as u64).sum(),
+        || flat.rows().flatten().map(|&c| c as u64).sum(),
+    );
+    compare(
+        runs,
+        "cols()",
+        || nested.cols().iter().flatten().map(|&c| c as u64).sum(),
+        || flat.cols().flatten().map(|&c| c as u64).sum(),
+    );
+}
+
+/// Add up every cell, visiting them in the order `get` is called with (a, b) for each a, b.
+fn sum_by(size: usize, get: impl Fn(usize, usize) -> Option<u8>) -> u64 {
+    let mut sum = 0;
+    for b in 0..size {
+        for a in 0..size {
+            sum += get(black_box(a), black_box(b)).unwrap() as u64;
+        }
+    }
+    sum
+}
+
+/// Add up the cells around every cell that isn't on the edge.
+fn neighbours(size: usize, get: impl Fn(usize, usize) -> Option<u8>) -> u64 {
+    let mut sum = 0;
+    for y in 1..size - 1 {
+        for x in 1..size - 1 {
+            for (dx, dy) in [
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (0, 1),
+                (2, 1),
+                (0, 2),
+                (1, 2),
+                (2, 2),
+            ] {
+                sum += get(black_box(x + dx - 1), y + dy - 1).unwrap() as u64;
+            }
+        }
+    }
+    sum
+}
+
+fn compare(runs: usize, name: &str, nested: impl Fn() -> u64, flat: impl Fn() -> u64) {
+    let (nested_time, nested_sum) = median(runs, nested);
+    let (flat_time, flat_sum) = median(runs, flat);
+    assert_eq!(nested_sum, flat_sum, "{name}: the layouts disagree");
+    println!(
+        "{name:<22} {:>12} {:>12}  {:.1}x",
+        format!("{nested_time:.2?}"),
+        format!("{flat_time:.2?}"),
+        nested_time.as_secs_f64() / flat_time.as_secs_f64()
+    );
+}
+
+fn median(runs: usize, f: impl Fn() -> u64) -> (Duration, u64) {
+    let mut result = 0;
+    let mut times: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            result = black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    (times[runs / 2], result)
+}
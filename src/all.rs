@@ -0,0 +1,340 @@
+//! Running every registered day at once, on a pool of worker threads (or one at a time, when
+//! the run is timed against a budget).
+
+use std::{
+    fmt::Display,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    answers::Status,
+    inputs,
+    isolate::Failure,
+    solution::{Day, PartRun, Year},
+};
+use termion::{color, style};
+
+use crate::{
+    output::{format_duration, ErrorKind, Format},
+    runner::{self, Runner},
+};
+
+/// What happened when one part was run.
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    pub input_file: String,
+    /// The run, or why there isn't one.
+    pub result: Result<PartRun, NoRun>,
+}
+
+/// Why a part wasn't run, or didn't finish.
+pub enum NoRun {
+    /// The day has no puzzle input yet.
+    NoInput,
+    /// The part panicked or timed out.
+    Failed(Failure),
+}
+
+impl Display for NoRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoRun::NoInput => write!(f, "no input"),
+            NoRun::Failed(failure) => write!(f, "{failure}"),
+        }
+    }
+}
+
+/// How many slow parts to point out when the budget is exceeded.
+const SLOWEST: usize = 3;
+
+/// Run both parts of every day in a year, then print each result, a summary table, and each
+/// day's share of the total time.  Days run in parallel, except with a budget, where they run one
+/// at a time so the parts don't slow each other down.  Returns false if the total is over the
+/// budget, or if any part failed or had no input, since its time can't be counted.
+pub fn run_all(runner: &mut Runner, year: &Year, budget: Option<Duration>) -> bool {
+    let t = Instant::now();
+    let workers = match budget {
+        Some(_) => 1,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let outcomes = solve_all(year, runner.share, runner.timeout, workers);
+    let total = t.elapsed();
+
+    let mut rows = vec![];
+    for outcome in &outcomes {
+        let status = match &outcome.result {
+            Ok(run) => Ok(runner.report(run, &outcome.input_file)),
+            Err(e) => {
+                let kind = match e {
+                    NoRun::NoInput => ErrorKind::NoInput,
+                    NoRun::Failed(failure) => ErrorKind::from(failure),
+                };
+                let e = e.to_string();
+                runner
+                    .printer
+                    .error(outcome.day, outcome.part, &outcome.input_file, kind, &e);
+                Err(e)
+            }
+        };
+        rows.push((outcome, status));
+    }
+
+    let color = runner.printer.format == Format::Pretty;
+    match runner.printer.format {
+        Format::Pretty | Format::Plain => {
+            print_summary(&rows, total, color);
+            print_shares(&outcomes, color);
+        }
+        Format::Json | Format::Csv => {}
+    }
+
+    let Some(budget) = budget else {
+        return true;
+    };
+    let used = time_used(&outcomes);
+    let failed: Vec<(&Outcome, &NoRun)> = outcomes
+        .iter()
+        .filter_map(|o| o.result.as_ref().err().map(|e| (o, e)))
+        .collect();
+    if used <= budget && failed.is_empty() {
+        if !matches!(runner.printer.format, Format::Json | Format::Csv) {
+            println!(
+                "within the {} budget, {:.1}% used",
+                format_duration(budget),
+                percent(used, budget)
+            );
+        }
+        return true;
+    }
+
+    // on stderr, so it's seen whatever the format
+    if used > budget {
+        eprintln!(
+            "over the {} budget by {}, slowest parts:",
+            format_duration(budget),
+            format_duration(used - budget)
+        );
+        for (day, part, time) in slowest(&outcomes, SLOWEST) {
+            eprintln!(
+                "  d{day}p{part} {} ({:.1}% of the total)",
+                format_duration(time),
+                percent(time, used)
+            );
+        }
+    }
+    if !failed.is_empty() {
+        eprintln!(
+            "can't tell if the {} budget was kept, some parts didn't finish:",
+            format_duration(budget)
+        );
+        for (outcome, e) in failed {
+            eprintln!("  d{}p{} {e}", outcome.day, outcome.part);
+        }
+    }
+    false
+}
+
+/// Solve both parts of every day in a year, spread across the given number of workers.  A part
+/// that panics or times out is recorded in its outcome without affecting the others.  Outcomes
+/// are returned in day and part order.
+pub fn solve_all(
+    year: &Year,
+    share: bool,
+    timeout: Option<Duration>,
+    workers: usize,
+) -> Vec<Outcome> {
+    let days = year.days;
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(vec![]);
+    let workers = workers.clamp(1, days.len().max(1));
+
+    thread::scope(|s| {
+        for _ in 0..workers {
+            s.spawn(|| {
+                while let Some(solution) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let day = solve_day(year.year, solution, share, timeout);
+                    outcomes.lock().unwrap().extend(day);
+                }
+            });
+        }
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|o| (o.day, o.part));
+    outcomes
+}
+
+fn solve_day(year: u16, solution: &Day, share: bool, timeout: Option<Duration>) -> Vec<Outcome> {
+    let input_file = inputs::input_path(year, solution.day);
+    let outcome = |part, result| Outcome {
+        day: solution.day,
+        part,
+        input_file: input_file.clone(),
+        result,
+    };
+
+    let Ok(input) = inputs::read(&input_file) else {
+        return vec![
+            outcome(1, Err(NoRun::NoInput)),
+            outcome(2, Err(NoRun::NoInput)),
+        ];
+    };
+
+    runner::solve(solution, None, input, share, timeout)
+        .into_iter()
+        .map(|(part, result)| outcome(part, result.map_err(NoRun::Failed)))
+        .collect()
+}
+
+/// Print a table of every outcome, followed by the wall-clock time of the whole run.
+fn print_summary(rows: &[(&Outcome, Result<Status, String>)], total: Duration, color: bool) {
+    let header = ["day", "part", "answer", "parse", "solve", "status"];
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|(outcome, status)| {
+            let (answer, parse, solve) = match &outcome.result {
+                Ok(run) => (
+                    first_line(&run.answer),
+                    run.parse
+                        .map(format_duration)
+                        .unwrap_or_else(|| "shared".to_string()),
+                    format_duration(run.solve),
+                ),
+                Err(_) => (String::new(), String::new(), String::new()),
+            };
+            let status = match status {
+                Ok(status) => status.to_string(),
+                Err(e) => e.clone(),
+            };
+            [
+                outcome.day.to_string(),
+                outcome.part.to_string(),
+                answer,
+                parse,
+                solve,
+                status,
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain([header[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let line = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!();
+    let header = line(&header.map(String::from));
+    if color {
+        println!("{}{header}{}", style::Bold, style::Reset);
+    } else {
+        println!("{header}");
+    }
+
+    for (row, (_, status)) in cells.iter().zip(rows) {
+        let row = line(row);
+        match status {
+            Ok(Status::Correct) | Ok(Status::Unknown) => println!("{row}"),
+            _ if color => println!("{}{row}{}", color::Fg(color::Red), style::Reset),
+            _ => println!("{row}"),
+        }
+    }
+
+    println!("total runtime {}", format_duration(total));
+}
+
+/// The time a part took to parse and solve.  A part that failed or has no input has no time to
+/// count.
+fn part_time(outcome: &Outcome) -> Option<Duration> {
+    let run = outcome.result.as_ref().ok()?;
+    Some(run.parse.unwrap_or_default() + run.solve)
+}
+
+/// The total parse and solve time of every part.  Unlike the wall-clock time, this doesn't
+/// depend on how many parts ran at once.
+fn time_used(outcomes: &[Outcome]) -> Duration {
+    outcomes.iter().filter_map(part_time).sum()
+}
+
+/// The n slowest parts as (day, part, time), slowest first.
+fn slowest(outcomes: &[Outcome], n: usize) -> Vec<(u8, u8, Duration)> {
+    let mut parts: Vec<(u8, u8, Duration)> = outcomes
+        .iter()
+        .filter_map(|o| Some((o.day, o.part, part_time(o)?)))
+        .collect();
+    parts.sort_by_key(|&(day, part, time)| (std::cmp::Reverse(time), day, part));
+    parts.truncate(n);
+    parts
+}
+
+fn percent(part: Duration, whole: Duration) -> f64 {
+    if whole.is_zero() {
+        0.0
+    } else {
+        part.as_secs_f64() / whole.as_secs_f64() * 100.0
+    }
+}
+
+/// Print each day's parse and solve time, added up over both parts, with its share of the total.
+/// Parts that failed aren't counted.
+fn print_shares(outcomes: &[Outcome], color: bool) {
+    let used = time_used(outcomes);
+    let mut days: Vec<(u8, Duration)> = vec![];
+    for outcome in outcomes {
+        // days that didn't finish a part have nothing to show
+        let Some(time) = part_time(outcome) else {
+            continue;
+        };
+        match days.last_mut() {
+            Some((day, total)) if *day == outcome.day => *total += time,
+            _ => days.push((outcome.day, time)),
+        }
+    }
+
+    println!();
+    let header = format!("{:<4} {:>10} {:>6}", "day", "time", "share");
+    if color {
+        println!("{}{header}{}", style::Bold, style::Reset);
+    } else {
+        println!("{header}");
+    }
+    for (day, time) in days {
+        let share = percent(time, used);
+        let bar = if color { "█" } else { "#" }.repeat((share / 5.0).round() as usize);
+        println!(
+            "{day:<4} {:>10} {:>5.1}% {bar}",
+            format_duration(time),
+            share
+        );
+    }
+    println!("total time used {}", format_duration(used));
+}
+
+/// Multi-line answers (like letters drawn in a grid) are cut down to their first line.
+fn first_line(answer: &str) -> String {
+    match answer.split_once('\n') {
+        Some((first, _)) => format!("{first}…"),
+        None => answer.to_string(),
+    }
+}
@@ -0,0 +1,426 @@
+//! Confirmed answers, stored in one file per year like `answers/2025.toml`, and in sidecar files
+//! next to the examples like `examples/2025/d1.answers`.
+//!
+//! Answers are keyed by day, input name, and part.  Each answers file is a small subset of TOML:
+//! one table per day and input, holding a `p1` and/or `p2` key.
+//!
+//! ```toml
+//! [d1.input]
+//! p1 = "1191"
+//! p2 = "6858"
+//! ```
+//!
+//! A sidecar holds the keys for its example alone.
+//!
+//! ```toml
+//! p1 = "3"
+//! p2 = "6"
+//! ```
+
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use crate::inputs;
+
+/// The path of a year's answers file, relative to the root of the repo.
+pub fn answers_path(year: u16) -> String {
+    format!("answers/{year}.toml")
+}
+
+/// The extension of an example's sidecar answers file.
+pub const SIDECAR_EXTENSION: &str = "answers";
+
+/// The path of the sidecar file holding an example's answers.
+pub fn sidecar_path(example: &str) -> String {
+    format!("{example}.{SIDECAR_EXTENSION}")
+}
+
+/// Whether an answer matches the stored one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Status {
+    Correct,
+    /// The answer doesn't match the stored one, which is included.
+    Wrong(String),
+    /// There's no stored answer to compare with.
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Correct => write!(f, "correct"),
+            Status::Wrong(_) => write!(f, "wrong"),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// A set of known answers, keyed by (day, input name, part).
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Answers(BTreeMap<(u8, String, u8), String>);
+
+impl Answers {
+    /// Load answers from a file.  A missing file is the same as an empty one.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Load a year's answers from the repo at root: the answers file, plus every example's
+    /// sidecar.
+    pub fn load_year(root: impl AsRef<Path>, year: u16) -> Result<Self, String> {
+        let root = root.as_ref();
+        let mut answers = Self::load(root.join(answers_path(year)))?;
+
+        let dir = root.join(inputs::example_dir(year));
+        let Ok(entries) = fs::read_dir(&dir) else {
+            return Ok(answers);
+        };
+        for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+            if path.extension().is_none_or(|ext| ext != SIDECAR_EXTENSION) {
+                continue;
+            }
+            let example = path.with_extension("");
+            let Some(day) = example_day(&example) else {
+                continue;
+            };
+
+            let name = input_name(day, &example.to_string_lossy());
+            let text = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+            for (i, line) in text.lines().enumerate() {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let (part, answer) = parse_entry(line)
+                    .map_err(|e| format!("{}: line {}: {e}", path.display(), i + 1))?;
+                answers.set(day, &name, part, &answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    /// Save a year's answers to the repo at root.  Example answers go to the examples' sidecars,
+    /// and the rest to the answers file.
+    pub fn save_year(&self, root: impl AsRef<Path>, year: u16) -> io::Result<()> {
+        let root = root.as_ref();
+        let (examples, others): (BTreeMap<_, _>, BTreeMap<_, _>) = self
+            .0
+            .clone()
+            .into_iter()
+            .partition(|((_, name, _), _)| name == "example" || name.starts_with("example-"));
+        Answers(others).save(root.join(answers_path(year)))?;
+
+        let mut sidecars: BTreeMap<String, String> = BTreeMap::new();
+        for ((day, name, part), answer) in examples {
+            let example = match name.strip_prefix("example-") {
+                Some(suffix) => format!("{}-{suffix}", inputs::example_path(year, day)),
+                None => inputs::example_path(year, day),
+            };
+            sidecars
+                .entry(sidecar_path(&example))
+                .or_default()
+                .push_str(&format!("p{part} = {}\n", quote(&answer)));
+        }
+        for (path, text) in sidecars {
+            let path = root.join(path);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, text)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        let mut table: Option<(u8, String)> = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            let err = |msg: &str| format!("line {}: {msg}", i + 1);
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| err("unclosed table header"))?;
+                let (day, input) = header
+                    .split_once('.')
+                    .ok_or_else(|| err("table header must look like [dN.input]"))?;
+                let day = day
+                    .trim()
+                    .strip_prefix('d')
+                    .and_then(|d| d.parse().ok())
+                    .ok_or_else(|| err("table header must start with a day, like d1"))?;
+                table = Some((day, unquote(input.trim())));
+                continue;
+            }
+
+            let (part, answer) = parse_entry(line).map_err(|e| err(&e))?;
+            let (day, input) = table
+                .clone()
+                .ok_or_else(|| err("answer given before any [dN.input] table"))?;
+
+            answers.set(day, &input, part, &answer);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        self.0
+            .get(&(day, input.to_string(), part))
+            .map(|a| a.as_str())
+    }
+
+    pub fn set(&mut self, day: u8, input: &str, part: u8, answer: &str) {
+        self.0
+            .insert((day, input.to_string(), part), answer.to_string());
+    }
+
+    /// Compare an answer with the stored one.
+    pub fn check(&self, day: u8, input: &str, part: u8, answer: &str) -> Status {
+        match self.get(day, input, part) {
+            Some(known) if known == answer => Status::Correct,
+            Some(known) => Status::Wrong(known.to_string()),
+            None => Status::Unknown,
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "# Confirmed answers, keyed by day, input name, and part."
+        )?;
+
+        let mut table = None;
+        for ((day, input, part), answer) in &self.0 {
+            if table != Some((day, input)) {
+                writeln!(f)?;
+                writeln!(f, "[d{day}.{}]", quote_key(input))?;
+                table = Some((day, input));
+            }
+            writeln!(f, "p{part} = {}", quote(answer))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The name an input file's answers are stored under.
+///
+/// The day's puzzle input (`input/YYYY/dN`) is `input` and its example (`examples/YYYY/dN`) is
+/// `example`.  Extra examples like `examples/YYYY/dN-test` are `example-test`.  Any other file is
+/// stored under its file name.
+pub fn input_name(day: u8, path: &str) -> String {
+    let path = Path::new(path);
+    let file = path
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut dirs = path
+        .parent()
+        .into_iter()
+        .flat_map(|p| p.components().rev())
+        .map(|c| c.as_os_str().to_string_lossy().to_string());
+    // skip over the year directory
+    let dir = match dirs.next() {
+        Some(year) if year.chars().all(|c| c.is_ascii_digit()) => dirs.next().unwrap_or_default(),
+        dir => dir.unwrap_or_default(),
+    };
+    let stem = format!("d{day}");
+
+    match dir.as_str() {
+        "input" if file == stem => "input".to_string(),
+        "examples" if file == stem => "example".to_string(),
+        "examples" => match file.strip_prefix(&format!("{stem}-")) {
+            Some(suffix) => format!("example-{suffix}"),
+            None => file,
+        },
+        _ => file,
+    }
+}
+
+/// Look up an answer in the repo's answers files.  Meant for tests, so it panics if the files
+/// can't be read or don't have the answer.
+pub fn expected(year: u16, day: u8, input: &str, part: u8) -> String {
+    let answers =
+        Answers::load_year(env!("CARGO_MANIFEST_DIR"), year).unwrap_or_else(|e| panic!("{e}"));
+    answers
+        .get(day, input, part)
+        .unwrap_or_else(|| panic!("no {year} answer for d{day}p{part} {input}"))
+        .to_string()
+}
+
+/// The day an example file belongs to, from a name like `d3` or `d3-test`.
+pub fn example_day(path: &Path) -> Option<u8> {
+    let name = path.file_name()?.to_str()?;
+    let day = name.strip_prefix('d')?;
+    let day = day.split_once('-').map_or(day, |(day, _)| day);
+    day.parse().ok()
+}
+
+/// Parse a `p1 = "answer"` line.
+fn parse_entry(line: &str) -> Result<(u8, String), String> {
+    let (key, value) = line.split_once('=').ok_or("expected a key = value pair")?;
+    let part = match key.trim() {
+        "p1" => 1,
+        "p2" => 2,
+        key => return Err(format!("unknown key {key:?}, expected p1 or p2")),
+    };
+    Ok((part, unquote(value.trim())))
+}
+
+/// Remove the quotes and escapes from a TOML string.  Bare values are returned as-is.
+fn unquote(s: &str) -> String {
+    let Some(inner) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+        return s.to_string();
+    };
+
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some(c) => out.push(c),
+                None => out.push('\\'),
+            },
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn quote(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        s.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace('\t', "\\t")
+    )
+}
+
+/// Quote a table key only if TOML requires it.
+fn quote_key(s: &str) -> String {
+    if !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        s.to_string()
+    } else {
+        quote(s)
+    }
+}
+
+#[cfg(test)]
+mod answers_tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let answers = Answers::parse(
+            r#"
+            # a comment
+            [d1.input]
+            p1 = "1191"
+            p2 = 6858
+
+            [d3."odd.name"]
+            p2 = "with \"quotes\""
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, "input", 1), Some("1191"));
+        assert_eq!(answers.get(1, "input", 2), Some("6858"));
+        assert_eq!(answers.get(3, "odd.name", 2), Some("with \"quotes\""));
+        assert_eq!(answers.get(3, "odd.name", 1), None);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        assert!(Answers::parse("p1 = 3").is_err());
+        assert!(Answers::parse("[d1.input]\np3 = 3").is_err());
+        assert!(Answers::parse("[input]\np1 = 3").is_err());
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let mut answers = Answers::default();
+        answers.set(10, "input", 1, "42");
+        answers.set(2, "example-test", 2, "line one\nline two");
+        answers.set(2, "custom.txt", 1, "x");
+
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+
+    #[test]
+    fn check_test() {
+        let mut answers = Answers::default();
+        answers.set(1, "input", 1, "1191");
+
+        assert_eq!(answers.check(1, "input", 1, "1191"), Status::Correct);
+        assert_eq!(
+            answers.check(1, "input", 1, "1190"),
+            Status::Wrong("1191".to_string())
+        );
+        assert_eq!(answers.check(1, "input", 2, "6858"), Status::Unknown);
+    }
+
+    #[test]
+    fn year_test() {
+        let root = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let mut answers = Answers::default();
+        answers.set(1, "input", 1, "1191");
+        answers.set(1, "example", 1, "3");
+        answers.set(1, "example", 2, "6");
+        answers.set(3, "example-p2-test", 2, "x");
+        answers.save_year(&root, 2025).unwrap();
+
+        let sidecar = root.join("examples/2025/d1.answers");
+        assert_eq!(
+            fs::read_to_string(sidecar).unwrap(),
+            "p1 = \"3\"\np2 = \"6\"\n"
+        );
+        assert!(root.join("examples/2025/d3-p2-test.answers").exists());
+        let toml = fs::read_to_string(root.join("answers/2025.toml")).unwrap();
+        assert!(!toml.contains("example"));
+
+        assert_eq!(Answers::load_year(&root, 2025).unwrap(), answers);
+    }
+
+    #[test]
+    fn input_name_test() {
+        assert_eq!(input_name(1, "input/2025/d1"), "input");
+        assert_eq!(input_name(1, "./input/2025/d1"), "input");
+        assert_eq!(input_name(1, "examples/2025/d1"), "example");
+        assert_eq!(input_name(1, "input/d1"), "input");
+        assert_eq!(input_name(3, "examples/2025/d3-p2-test"), "example-p2-test");
+        assert_eq!(input_name(3, "examples/2025/d31"), "d31");
+        assert_eq!(input_name(3, "/tmp/custom"), "custom");
+    }
+}
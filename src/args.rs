@@ -1,51 +1,688 @@
+use std::{ffi::OsString, fmt::Display, time::Duration};
+
+use aoc::{
+    inputs,
+    solution::{self, Day, Year},
+    YEARS,
+};
+
+use crate::output::Format;
+
+/// The first year of Advent of Code.
+const FIRST_YEAR: u16 = 2015;
+
+/// The most days an event has had.
+const LAST_DAY: u8 = 25;
+
 pub const HELP: &str = "\
-Usage: aoc2025 -d <day> [-p <part>] [-e] [-i <input>]
+Usage: aoc [-y <year>] -d <day> [-p <part>] [-e | -i <input>...] [-s] [-f <format>] [-t <time>]
+           [-w] [--save]
+       aoc [-y <year>] --all [-s] [-f <format>] [-t <time>] [-b <time>]
+       aoc bench [-y <year>] [-d <day>] [-p <part>] [-e | -i <input>] [-n <runs>] [-w <runs>]
+                 [-t <time>] [--threshold <percent>] [--save]
+       aoc scaffold [-y <year>] -d <day> [--force]
+       aoc fetch [-y <year>] -d <day>
+       aoc submit [-y <year>] -d <day> -p <part> [-t <time>] [<answer>]
+       aoc examples [-y <year>] -d <day> [-i <page>] [--force]
+       aoc dashboard [-y <year>] [-t <time>]
+       aoc report [-y <year>] [-n <runs>] [-w <runs>] [-t <time>] [-o <file>] [--html <file>]
+                  [--readme]
 
 The CLI arguments allowed.
 
 Options:
+  -y, --year        specifies the year (default $AOC_YEAR, or the latest year)
   -d, --day         specifies the day
+  -a, --all         run every day in parallel and print a summary table
   -p, --part        specifies the part
-  -e, --example     use the day's example input from examples/
-  -i, --input       specify an alternate input file
+  -e, --example     use the day's example input from examples/<year>/
+  -i, --input       specify an alternate input file, - for stdin, or a quoted pattern
+                    like 'examples/2025/d3*' (repeat to run several inputs)
+  -s, --share       parse the input once and share it between both parts
+  -f, --format      output format: pretty (default), plain, json, or csv
+  -t, --timeout     give up on any part still running after this long, eg 10s or 500ms
+  -b, --budget      with --all, the most time every part together may take, eg 1s; exits
+                    non-zero and lists the slowest parts if they take longer
+  -w, --watch       run again whenever the day's source, input, examples, or answers change,
+                    and show how the answers changed
+      --save        record the answers as correct in answers/<year>.toml, or the examples'
+                    sidecar files
   -h, --help        display usage information
-";
 
-pub const INPUT_CONFLICT: &str = "\
-Error: -i/--input and -e/--example can't be used together.
+Bench options:
+  -d, --day         benchmark only this day (all days with input if omitted)
+  -n, --runs        timed runs per part (default 100)
+  -w, --warmup      untimed runs per part before timing starts (default 10)
+  -t, --timeout     fail a part if its runs together take longer than this (default 60s)
+      --threshold   percent slowdown from the baseline to flag (default 10)
+      --save        save the results as the new baseline
+
+Scaffold options:
+  -d, --day         create this day's source and example files and register it (1 through 25)
+      --force       overwrite the day's source file if it already exists
+
+Fetch options:
+  -d, --day         download this day's input to input/<year>/ unless it's already there,
+                    logged in with $AOC_SESSION (from $AOC_BASE_URL if it's set)
+
+Submit options:
+  -d, --day         the day to submit an answer for
+  -p, --part        the part to submit an answer for
+  -t, --timeout     when solving the part for its answer, give up after this long (default 60s)
+  <answer>          the answer to submit (default: the day's answer for its puzzle input)
+
+Examples options:
+  -d, --day         save the examples from this day's puzzle page to examples/<year>/, and
+                    their answers to sidecar files next to them, like dN.answers
+  -i, --input       a saved puzzle page to read (default: download it from the site)
+      --force       overwrite example files and answers that already exist
+
+Report options:
+  -n, --runs        timed runs per part, for the median (default 10)
+  -w, --warmup      untimed runs per part before timing starts (default 1)
+  -t, --timeout     give up on any part still running after this long, or whose timed runs
+                    together take longer (default 10s)
+  -o, --output      write the Markdown table to this file (default: print it)
+      --html        also write a standalone HTML page to this file
+      --readme      replace the table between the report markers in README.md
+
+Dashboard options:
+  -t, --timeout     give up on any part still running after this long (default 30s)
+
+Dashboard keys:
+  up/down, j/k      select a day
+  enter, r          run the selected day
+  a                 run every day
+  e                 switch between the puzzle inputs and the examples
+  d, tab            show or hide the selected day's last run, with its output and any panic
+  q, esc            quit
 ";
 
 /// The CLI arguments allowed.
 pub struct Args {
-    /// specifies the day (255 runs all parts)
-    pub day: u8,
-    /// specifies the part
+    /// specifies the year
+    pub year: u16,
+    /// specifies the day; only None when running all days
+    pub day: Option<u8>,
+    /// run every day in parallel and print a summary table
+    pub all: bool,
+    /// specifies the part (1 or 2)
     pub part: Option<u8>,
-    /// use the day's example input from examples/
+    /// use the day's example input from examples/<year>/
+    pub example: bool,
+    /// specify alternate input files
+    pub input: Vec<String>,
+    /// parse the input once and share it between both parts
+    pub share: bool,
+    /// output format
+    pub format: Format,
+    /// record the answers as correct in answers/<year>.toml, or the examples' sidecars
+    pub save: bool,
+    /// give up on any part still running after this long
+    pub timeout: Option<Duration>,
+    /// run again whenever the day's files change
+    pub watch: bool,
+    /// the most time every part together may take, when running all days
+    pub budget: Option<Duration>,
+}
+
+/// The bench subcommand's arguments.
+pub struct BenchArgs {
+    /// benchmark days from this year
+    pub year: u16,
+    /// benchmark only this day
+    pub day: Option<u8>,
+    /// benchmark only this part (1 or 2)
+    pub part: Option<u8>,
+    /// use the day's example input from examples/<year>/
     pub example: bool,
     /// specify an alternate input file
     pub input: Option<String>,
+    /// timed runs per part
+    pub runs: usize,
+    /// untimed runs per part before timing starts
+    pub warmup: usize,
+    /// give up on a part whose runs together are still going after this long
+    pub timeout: Duration,
+    /// percent slowdown from the baseline to flag
+    pub threshold: f64,
+    /// save the results as the new baseline
+    pub save: bool,
+}
+
+/// The scaffold subcommand's arguments.
+pub struct ScaffoldArgs {
+    /// the year to add the day to, which doesn't have to be registered yet
+    pub year: u16,
+    /// the day to create
+    pub day: u8,
+    /// overwrite the day's source file if it already exists
+    pub force: bool,
 }
 
-pub fn parse_args() -> Result<Args, pico_args::Error> {
-    let mut pargs = pico_args::Arguments::from_env();
+/// The fetch subcommand's arguments.
+pub struct FetchArgs {
+    /// the year to download from, which doesn't have to be registered yet
+    pub year: u16,
+    /// the day whose input to download
+    pub day: u8,
+}
 
+/// The submit subcommand's arguments.
+pub struct SubmitArgs {
+    /// the year to submit to, which doesn't have to be registered
+    pub year: u16,
+    /// the day to submit an answer for
+    pub day: u8,
+    /// the part to submit an answer for (1 or 2)
+    pub part: u8,
+    /// give up on solving the part after this long
+    pub timeout: Duration,
+    /// the answer, or None to solve the part and submit its answer
+    pub answer: Option<String>,
+}
+
+/// The examples subcommand's arguments.
+pub struct ExamplesArgs {
+    /// the year of the puzzle, which doesn't have to be registered
+    pub year: u16,
+    /// the day of the puzzle
+    pub day: u8,
+    /// a saved copy of the puzzle page, or None to download it
+    pub page: Option<String>,
+    /// overwrite example files and answers that already exist
+    pub force: bool,
+}
+
+/// The report subcommand's arguments.
+pub struct ReportArgs {
+    /// report on the days from this year
+    pub year: u16,
+    /// timed runs per part
+    pub runs: usize,
+    /// untimed runs per part before timing starts
+    pub warmup: usize,
+    /// give up on any part still running after this long
+    pub timeout: Duration,
+    /// write the Markdown table to this file instead of printing it
+    pub output: Option<String>,
+    /// also write a standalone HTML page to this file
+    pub html: Option<String>,
+    /// replace the table between the report markers in README.md
+    pub readme: bool,
+}
+
+/// The dashboard subcommand's arguments.
+pub struct DashboardArgs {
+    /// show the days from this year
+    pub year: u16,
+    /// give up on any part still running after this long
+    pub timeout: Duration,
+}
+
+pub enum Command {
+    Help,
+    Run(Args),
+    Bench(BenchArgs),
+    Scaffold(ScaffoldArgs),
+    Fetch(FetchArgs),
+    Submit(SubmitArgs),
+    Examples(ExamplesArgs),
+    Dashboard(DashboardArgs),
+    Report(ReportArgs),
+}
+
+/// Why the CLI arguments were rejected.
+#[derive(Debug)]
+pub enum ArgsError {
+    /// The year given isn't a registered year.
+    UnknownYear { given: String, years: Vec<u16> },
+    /// The year given isn't an Advent of Code year at all.
+    BadYear(String),
+    /// No day was given, and one is required.
+    MissingDay,
+    /// The day given isn't a registered day.
+    DayOutOfRange { given: String, first: u8, last: u8 },
+    /// No part was given, and one is required.
+    MissingPart,
+    /// The part given isn't 1 or 2.
+    BadPart(String),
+    /// Two options were given that can't be used together.
+    Conflict(&'static str, &'static str),
+    /// Arguments were left over that aren't recognized.
+    Unknown(Vec<String>),
+    /// An option's value couldn't be parsed.
+    Invalid(pico_args::Error),
+}
+
+impl ArgsError {
+    /// The exit code for this error.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ArgsError::Invalid(_) => 2,
+            ArgsError::MissingDay => 3,
+            ArgsError::DayOutOfRange { .. } => 4,
+            ArgsError::MissingPart | ArgsError::BadPart(_) => 5,
+            ArgsError::Conflict(_, _) => 6,
+            ArgsError::Unknown(_) => 7,
+            ArgsError::UnknownYear { .. } | ArgsError::BadYear(_) => 8,
+        }
+    }
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgsError::UnknownYear { given, years } => {
+                let years: Vec<String> = years.iter().map(|y| y.to_string()).collect();
+                write!(f, "year must be one of {}, got {given:?}", years.join(", "))
+            }
+            ArgsError::BadYear(given) => {
+                write!(f, "year must be {FIRST_YEAR} or later, got {given:?}")
+            }
+            ArgsError::MissingDay => write!(f, "a day is required, use -d <day>"),
+            ArgsError::DayOutOfRange { given, first, last } => {
+                write!(f, "day must be {first} through {last}, got {given:?}")
+            }
+            ArgsError::MissingPart => write!(f, "a part is required, use -p <part>"),
+            ArgsError::BadPart(given) => write!(f, "part must be 1 or 2, got {given:?}"),
+            ArgsError::Conflict(a, b) => write!(f, "{a} and {b} can't be used together"),
+            ArgsError::Unknown(args) => write!(f, "unknown arguments: {}", args.join(" ")),
+            ArgsError::Invalid(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<pico_args::Error> for ArgsError {
+    fn from(e: pico_args::Error) -> Self {
+        ArgsError::Invalid(e)
+    }
+}
+
+pub fn parse_args() -> Result<Command, ArgsError> {
+    let default_year = std::env::var("AOC_YEAR").ok();
+    parse(pico_args::Arguments::from_env(), YEARS, default_year)
+}
+
+/// Parse and validate arguments, checking years and days against the given registry.  The
+/// default year is used when there's no -y/--year, falling back to the latest year.
+fn parse(
+    mut pargs: pico_args::Arguments,
+    years: &[Year],
+    default_year: Option<String>,
+) -> Result<Command, ArgsError> {
     if pargs.contains(["-h", "--help"]) {
-        print!("{HELP}");
-        std::process::exit(0);
+        return Ok(Command::Help);
     }
 
+    let command = match pargs.subcommand()?.as_deref() {
+        None => {
+            let year = parse_year(&mut pargs, years, default_year)?;
+            Command::Run(parse_run(&mut pargs, year)?)
+        }
+        Some("bench") => {
+            let year = parse_year(&mut pargs, years, default_year)?;
+            Command::Bench(parse_bench(&mut pargs, year)?)
+        }
+        Some("scaffold") => Command::Scaffold(ScaffoldArgs {
+            year: parse_any_year(&mut pargs, years, default_year)?,
+            day: parse_any_day(&mut pargs)?,
+            force: pargs.contains("--force"),
+        }),
+        Some("fetch") => Command::Fetch(FetchArgs {
+            year: parse_any_year(&mut pargs, years, default_year)?,
+            day: parse_any_day(&mut pargs)?,
+        }),
+        Some("submit") => Command::Submit(SubmitArgs {
+            year: parse_any_year(&mut pargs, years, default_year)?,
+            day: parse_any_day(&mut pargs)?,
+            part: parse_part(&mut pargs)?.ok_or(ArgsError::MissingPart)?,
+            timeout: pargs
+                .opt_value_from_fn(["-t", "--timeout"], parse_duration)?
+                .unwrap_or(Duration::from_secs(60)),
+            // the answer comes last, after every option has been taken out
+            answer: pargs.opt_free_from_str()?,
+        }),
+        Some("examples") => Command::Examples(ExamplesArgs {
+            year: parse_any_year(&mut pargs, years, default_year)?,
+            day: parse_any_day(&mut pargs)?,
+            page: pargs.opt_value_from_str(["-i", "--input"])?,
+            force: pargs.contains("--force"),
+        }),
+        Some("dashboard") => Command::Dashboard(DashboardArgs {
+            year: parse_year(&mut pargs, years, default_year)?.year,
+            timeout: pargs
+                .opt_value_from_fn(["-t", "--timeout"], parse_duration)?
+                .unwrap_or(Duration::from_secs(30)),
+        }),
+        Some("report") => Command::Report(ReportArgs {
+            year: parse_year(&mut pargs, years, default_year)?.year,
+            runs: pargs.opt_value_from_str(["-n", "--runs"])?.unwrap_or(10),
+            warmup: pargs.opt_value_from_str(["-w", "--warmup"])?.unwrap_or(1),
+            timeout: pargs
+                .opt_value_from_fn(["-t", "--timeout"], parse_duration)?
+                .unwrap_or(Duration::from_secs(10)),
+            output: pargs.opt_value_from_str(["-o", "--output"])?,
+            html: pargs.opt_value_from_str("--html")?,
+            readme: pargs.contains("--readme"),
+        }),
+        Some(other) => return Err(ArgsError::Unknown(vec![other.to_string()])),
+    };
+
+    let rest = pargs.finish();
+    if !rest.is_empty() {
+        return Err(ArgsError::Unknown(lossy(rest)));
+    }
+
+    Ok(command)
+}
+
+fn parse_run(pargs: &mut pico_args::Arguments, year: &Year) -> Result<Args, ArgsError> {
     let args = Args {
-        day: pargs.value_from_str(["-d", "--day"])?,
-        part: pargs.value_from_str(["-p", "--part"]).ok(),
+        year: year.year,
+        day: parse_day(pargs, year.days)?,
+        all: pargs.contains(["-a", "--all"]),
+        part: parse_part(pargs)?,
         example: pargs.contains(["-e", "--example"]),
-        input: pargs.opt_value_from_str(["-i", "--input"])?,
+        input: pargs.values_from_str(["-i", "--input"])?,
+        share: pargs.contains(["-s", "--share"]),
+        format: pargs
+            .opt_value_from_str(["-f", "--format"])?
+            .unwrap_or_default(),
+        save: pargs.contains("--save"),
+        timeout: pargs.opt_value_from_fn(["-t", "--timeout"], parse_duration)?,
+        watch: pargs.contains(["-w", "--watch"]),
+        budget: pargs.opt_value_from_fn(["-b", "--budget"], parse_duration)?,
     };
 
-    if pargs.contains(["-e", "--example"]) && pargs.contains(["-i", "--input"]) {
-        print!("{INPUT_CONFLICT}");
-        std::process::exit(1);
+    if args.all {
+        if args.day.is_some() {
+            return Err(ArgsError::Conflict("--all", "-d/--day"));
+        }
+        if args.part.is_some() {
+            return Err(ArgsError::Conflict("--all", "-p/--part"));
+        }
+        if args.example {
+            return Err(ArgsError::Conflict("--all", "-e/--example"));
+        }
+        if !args.input.is_empty() {
+            return Err(ArgsError::Conflict("--all", "-i/--input"));
+        }
+        if args.watch {
+            return Err(ArgsError::Conflict("--all", "-w/--watch"));
+        }
+    } else if args.day.is_none() {
+        return Err(ArgsError::MissingDay);
+    } else if args.budget.is_some() {
+        return Err(ArgsError::Conflict("-d/--day", "-b/--budget"));
+    }
+
+    if args.example && !args.input.is_empty() {
+        return Err(ArgsError::Conflict("-e/--example", "-i/--input"));
+    }
+    if args.watch && args.input.iter().any(|i| i == inputs::STDIN) {
+        return Err(ArgsError::Conflict("-w/--watch", "-i -"));
     }
 
     Ok(args)
 }
+
+fn parse_bench(pargs: &mut pico_args::Arguments, year: &Year) -> Result<BenchArgs, ArgsError> {
+    let bench = BenchArgs {
+        year: year.year,
+        day: parse_day(pargs, year.days)?,
+        part: parse_part(pargs)?,
+        example: pargs.contains(["-e", "--example"]),
+        input: pargs.opt_value_from_str(["-i", "--input"])?,
+        runs: pargs.opt_value_from_str(["-n", "--runs"])?.unwrap_or(100),
+        warmup: pargs.opt_value_from_str(["-w", "--warmup"])?.unwrap_or(10),
+        timeout: pargs
+            .opt_value_from_fn(["-t", "--timeout"], parse_duration)?
+            .unwrap_or(Duration::from_secs(60)),
+        threshold: pargs.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+        save: pargs.contains("--save"),
+    };
+
+    if bench.example && bench.input.is_some() {
+        return Err(ArgsError::Conflict("-e/--example", "-i/--input"));
+    }
+
+    Ok(bench)
+}
+
+/// Parse -y/--year for subcommands that work on days that haven't been solved yet, so any
+/// Advent of Code year is allowed.
+fn parse_any_year(
+    pargs: &mut pico_args::Arguments,
+    years: &[Year],
+    default: Option<String>,
+) -> Result<u16, ArgsError> {
+    match pargs
+        .opt_value_from_str::<_, String>(["-y", "--year"])?
+        .or(default)
+    {
+        Some(given) => match given.parse() {
+            Ok(year) if year >= FIRST_YEAR => Ok(year),
+            _ => Err(ArgsError::BadYear(given)),
+        },
+        None => Ok(years.last().expect("at least one year is registered").year),
+    }
+}
+
+/// Parse a required -d/--day for subcommands that work on days that haven't been solved yet, so
+/// any day of the event is allowed.
+fn parse_any_day(pargs: &mut pico_args::Arguments) -> Result<u8, ArgsError> {
+    let Some(given) = pargs.opt_value_from_str::<_, String>(["-d", "--day"])? else {
+        return Err(ArgsError::MissingDay);
+    };
+
+    match given.parse() {
+        Ok(day) if (1..=LAST_DAY).contains(&day) => Ok(day),
+        _ => Err(ArgsError::DayOutOfRange {
+            given,
+            first: 1,
+            last: LAST_DAY,
+        }),
+    }
+}
+
+/// Parse -y/--year, checking that the year is in the registry.
+fn parse_year<'a>(
+    pargs: &mut pico_args::Arguments,
+    years: &'a [Year],
+    default: Option<String>,
+) -> Result<&'a Year, ArgsError> {
+    let given = pargs
+        .opt_value_from_str::<_, String>(["-y", "--year"])?
+        .or(default);
+    let Some(given) = given else {
+        return Ok(years.last().expect("at least one year is registered"));
+    };
+
+    match given
+        .parse()
+        .ok()
+        .and_then(|year| solution::find_year(years, year))
+    {
+        Some(year) => Ok(year),
+        None => Err(ArgsError::UnknownYear {
+            given,
+            years: years.iter().map(|y| y.year).collect(),
+        }),
+    }
+}
+
+/// Parse -d/--day, checking that the day is in the registry.
+fn parse_day(pargs: &mut pico_args::Arguments, days: &[Day]) -> Result<Option<u8>, ArgsError> {
+    let Some(given) = pargs.opt_value_from_str::<_, String>(["-d", "--day"])? else {
+        return Ok(None);
+    };
+
+    match given.parse().ok().and_then(|day| solution::find(days, day)) {
+        Some(solution) => Ok(Some(solution.day)),
+        None => Err(ArgsError::DayOutOfRange {
+            given,
+            first: days.iter().map(|d| d.day).min().unwrap_or(0),
+            last: days.iter().map(|d| d.day).max().unwrap_or(0),
+        }),
+    }
+}
+
+/// Parse -p/--part, checking that it's 1 or 2.
+fn parse_part(pargs: &mut pico_args::Arguments) -> Result<Option<u8>, ArgsError> {
+    match pargs
+        .opt_value_from_str::<_, String>(["-p", "--part"])?
+        .as_deref()
+    {
+        None => Ok(None),
+        Some("1") => Ok(Some(1)),
+        Some("2") => Ok(Some(2)),
+        Some(given) => Err(ArgsError::BadPart(given.to_string())),
+    }
+}
+
+fn lossy(args: Vec<OsString>) -> Vec<String> {
+    args.into_iter()
+        .map(|a| a.to_string_lossy().to_string())
+        .collect()
+}
+
+/// Parse a duration like "500ms", "10s", or "2m".  A bare number is in seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (n, unit) = s.split_at(split);
+    let n: f64 = n
+        .parse()
+        .map_err(|_| format!("invalid duration {s:?}, expected something like 10s or 500ms"))?;
+
+    let secs = match unit {
+        "ms" => n / 1000.0,
+        "" | "s" => n,
+        "m" => n * 60.0,
+        _ => {
+            return Err(format!(
+                "unknown unit {unit:?} in duration {s:?}, expected ms, s, or m"
+            ))
+        }
+    };
+
+    Duration::try_from_secs_f64(secs).map_err(|e| format!("invalid duration {s:?}: {e}"))
+}
+
+#[cfg(test)]
+mod args_tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Command, ArgsError> {
+        let args = args.iter().map(OsString::from).collect();
+        parse(pico_args::Arguments::from_vec(args), YEARS, None)
+    }
+
+    fn exit_code(a: &[&str]) -> i32 {
+        match args(a) {
+            Ok(_) => 0,
+            Err(e) => e.exit_code(),
+        }
+    }
+
+    #[test]
+    fn valid_args_test() {
+        let Ok(Command::Run(run)) = args(&["-d", "3", "-p", "2", "-i", "a", "-i", "b"]) else {
+            panic!("expected a run command");
+        };
+        assert_eq!(run.year, 2025);
+        assert_eq!(run.day, Some(3));
+        assert_eq!(run.part, Some(2));
+        assert_eq!(run.input, vec!["a", "b"]);
+
+        assert!(matches!(args(&["--all"]), Ok(Command::Run(_))));
+        assert!(matches!(
+            args(&["bench", "-t", "2s"]),
+            Ok(Command::Bench(BenchArgs { timeout, .. })) if timeout == Duration::from_secs(2)
+        ));
+        assert!(matches!(
+            args(&["scaffold", "-y", "2030", "-d", "25"]),
+            Ok(Command::Scaffold(ScaffoldArgs {
+                year: 2030,
+                day: 25,
+                force: false
+            }))
+        ));
+        assert!(matches!(args(&["-d", "1", "--help"]), Ok(Command::Help)));
+        assert!(matches!(
+            args(&["dashboard", "-t", "5s"]),
+            Ok(Command::Dashboard(DashboardArgs { year: 2025, timeout })) if timeout == Duration::from_secs(5)
+        ));
+
+        let Ok(Command::Submit(submit)) = args(&["submit", "-d", "4", "-p", "2", "1234"]) else {
+            panic!("expected a submit command");
+        };
+        assert_eq!((submit.day, submit.part), (4, 2));
+        assert_eq!(submit.answer.as_deref(), Some("1234"));
+    }
+
+    #[test]
+    fn default_year_test() {
+        let from_env = |year: &str| {
+            let args = vec![OsString::from("--all")];
+            parse(
+                pico_args::Arguments::from_vec(args),
+                YEARS,
+                Some(year.to_string()),
+            )
+        };
+        assert!(matches!(
+            from_env("2025"),
+            Ok(Command::Run(Args { year: 2025, .. }))
+        ));
+        assert!(matches!(
+            from_env("1999"),
+            Err(ArgsError::UnknownYear { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_args_test() {
+        assert_eq!(exit_code(&["-p", "1"]), 3);
+        assert_eq!(exit_code(&["-d", "0"]), 4);
+        assert_eq!(exit_code(&["-d", "99"]), 4);
+        assert_eq!(exit_code(&["-d", "one"]), 4);
+        assert_eq!(exit_code(&["bench", "-d", "13"]), 4);
+        assert_eq!(exit_code(&["-d", "1", "-p", "3"]), 5);
+        assert_eq!(exit_code(&["-d", "1", "-e", "-i", "x"]), 6);
+        assert_eq!(exit_code(&["-d", "1", "--all"]), 6);
+        assert_eq!(exit_code(&["--all", "--watch"]), 6);
+        assert_eq!(exit_code(&["-d", "1", "-b", "1s"]), 6);
+        assert_eq!(exit_code(&["--all", "-b", "soon"]), 2);
+        assert_eq!(exit_code(&["-d", "1", "-w", "-i", "-"]), 6);
+        assert_eq!(exit_code(&["-d", "1", "--bogus"]), 7);
+        assert_eq!(exit_code(&["frobnicate", "-d", "1"]), 7);
+        assert_eq!(exit_code(&["-d", "1", "-f", "xml"]), 2);
+        assert_eq!(exit_code(&["-d", "1", "-t", "soon"]), 2);
+        assert_eq!(exit_code(&["-y", "1999", "-d", "1"]), 8);
+        assert_eq!(exit_code(&["bench", "--year", "2024"]), 8);
+        assert_eq!(exit_code(&["scaffold"]), 3);
+        assert_eq!(exit_code(&["scaffold", "-d", "26"]), 4);
+        assert_eq!(exit_code(&["scaffold", "-y", "1999", "-d", "1"]), 8);
+        assert_eq!(exit_code(&["fetch", "-d", "0"]), 4);
+        assert_eq!(exit_code(&["submit", "-d", "1", "123"]), 5);
+        assert_eq!(exit_code(&["submit", "-d", "1", "-p", "1", "12", "34"]), 7);
+        assert_eq!(exit_code(&["dashboard", "-y", "1999"]), 8);
+        assert_eq!(exit_code(&["report", "-n", "many"]), 2);
+    }
+
+    #[test]
+    fn parse_duration_test() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("1e30").is_err());
+        assert!(parse_duration(&format!("{}s", "9".repeat(30))).is_err());
+        assert_eq!(exit_code(&["-d", "1", "-t", &"9".repeat(30)]), 2);
+    }
+}
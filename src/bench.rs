@@ -0,0 +1,296 @@
+//! Repeated timing of solutions, with a saved baseline for catching regressions.
+
+use std::{collections::BTreeMap, fs, time::Duration};
+
+use aoc::{
+    answers, inputs,
+    isolate::{isolate, Failure},
+    solution::{self, Day},
+    YEARS,
+};
+use termion::{color, style};
+
+use crate::{args::BenchArgs, output::format_duration};
+
+/// Where the median of each benchmarked part is saved.
+pub const BASELINE_FILE: &str = ".bench_baseline";
+
+/// Summary statistics over a set of timed runs.
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "can't summarize zero runs");
+        samples.sort();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let mean = samples.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            runs: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Time parsing and solving one part.  The warmup runs are discarded.  All the runs happen on
+/// one isolated thread, so a panic is returned as a failure, and so is still running when the
+/// timeout (for all the runs together) is up.
+pub fn measure(
+    solution: &Day,
+    part: u8,
+    input: &str,
+    runs: usize,
+    warmup: usize,
+    timeout: Option<Duration>,
+) -> Result<Stats, Failure> {
+    let solution = *solution;
+    let input = input.to_string();
+    isolate(format!("d{}p{part}", solution.day), timeout, move || {
+        for _ in 0..warmup {
+            solution.run(part, input.clone());
+        }
+
+        let samples = (0..runs.max(1))
+            .map(|_| {
+                let run = solution.run(part, input.clone());
+                run.parse.unwrap_or_default() + run.solve
+            })
+            .collect();
+
+        Stats::new(samples)
+    })
+}
+
+/// Median times from a previous bench run, keyed by (year, day, part, input name), where the
+/// input name is the one its answers are stored under, like `input` or `example`.
+#[derive(Default)]
+pub struct Baseline(BTreeMap<(u16, u8, u8, String), Duration>);
+
+impl Baseline {
+    pub fn load(path: &str) -> Self {
+        let Ok(text) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+
+        Self(
+            text.lines()
+                .filter(|line| !line.starts_with('#'))
+                .filter_map(|line| {
+                    let (key, nanos) = line.rsplit_once(' ')?;
+                    let (year, key) = key.split_once('/')?;
+                    let (key, name) = key.split_once('/')?;
+                    let (day, part) = key.strip_prefix('d')?.split_once('p')?;
+                    Some((
+                        (
+                            year.parse().ok()?,
+                            day.parse().ok()?,
+                            part.parse().ok()?,
+                            name.to_string(),
+                        ),
+                        Duration::from_nanos(nanos.trim().parse().ok()?),
+                    ))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let mut text = "# median nanoseconds per part, written by `aoc bench`\n".to_string();
+        for ((year, day, part, name), median) in &self.0 {
+            text.push_str(&format!(
+                "{year}/d{day}p{part}/{name} {}\n",
+                median.as_nanos()
+            ));
+        }
+        fs::write(path, text)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8, name: &str) -> Option<Duration> {
+        self.0.get(&(year, day, part, name.to_string())).copied()
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, part: u8, name: &str, median: Duration) {
+        self.0.insert((year, day, part, name.to_string()), median);
+    }
+}
+
+/// Run the bench subcommand.  Returns true if any part regressed beyond the threshold, or
+/// failed.
+pub fn bench(args: &BenchArgs) -> bool {
+    let year =
+        solution::find_year(YEARS, args.year).expect("the year is checked when parsing arguments");
+    let days: Vec<&Day> = match args.day {
+        Some(day) => vec![year
+            .day(day)
+            .expect("the day is checked when parsing arguments")],
+        None => year.days.iter().collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut baseline = Baseline::load(BASELINE_FILE);
+    let mut changed = false;
+    let mut regressed = false;
+
+    for solution in days {
+        let input_file = if let Some(input) = &args.input {
+            input.clone()
+        } else if args.example {
+            inputs::example_path(year.year, solution.day)
+        } else {
+            inputs::input_path(year.year, solution.day)
+        };
+
+        let Ok(input) = inputs::read(&input_file) else {
+            eprintln!("skipping day {}: can't read {input_file}", solution.day);
+            continue;
+        };
+
+        let name = answers::input_name(solution.day, &input_file);
+        for &part in &parts {
+            let stats = match measure(
+                solution,
+                part,
+                &input,
+                args.runs,
+                args.warmup,
+                Some(args.timeout),
+            ) {
+                Ok(stats) => stats,
+                Err(failure) => {
+                    // a part that stopped working is worse than one that got slower
+                    eprintln!(
+                        "{red}d{}p{part} failed:{reset} {failure}",
+                        solution.day,
+                        red = color::Fg(color::Red),
+                        reset = style::Reset
+                    );
+                    regressed = true;
+                    continue;
+                }
+            };
+            let previous = baseline.get(year.year, solution.day, part, &name);
+            regressed |= print_stats(solution.day, part, &stats, previous, args.threshold);
+
+            if args.save || previous.is_none() {
+                baseline.set(year.year, solution.day, part, &name, stats.median);
+                changed = true;
+            }
+        }
+    }
+
+    if changed {
+        match baseline.save(BASELINE_FILE) {
+            Ok(()) => println!("baseline saved to {BASELINE_FILE}"),
+            Err(e) => eprintln!("Error: couldn't save {BASELINE_FILE}: {e}"),
+        }
+    }
+
+    regressed
+}
+
+/// Print one part's stats, compared with its baseline if there is one.  Returns true if the
+/// median is slower than the baseline by more than threshold percent.
+fn print_stats(
+    day: u8,
+    part: u8,
+    stats: &Stats,
+    baseline: Option<Duration>,
+    threshold: f64,
+) -> bool {
+    let change = baseline
+        .map(|base| (stats.median.as_secs_f64() - base.as_secs_f64()) / base.as_secs_f64() * 100.0);
+    let regressed = change.is_some_and(|c| c > threshold);
+
+    let comparison = match change {
+        Some(c) if regressed => format!(
+            " {red}{c:+.1}% regression{reset}",
+            red = color::Fg(color::Red),
+            reset = style::Reset
+        ),
+        Some(c) => format!(
+            " {grey}{c:+.1}%{reset}",
+            grey = color::Fg(color::LightBlack),
+            reset = style::Reset
+        ),
+        None => String::new(),
+    };
+
+    println!(
+        "{green}🎄{reset} {blue}d{day}p{part}{reset} median {median} {grey}(min {min}, mean {mean}, σ {stddev}, {runs} runs){reset}{comparison}",
+        blue = color::Fg(color::Blue),
+        green = color::Fg(color::Green),
+        grey = color::Fg(color::LightBlack),
+        reset = style::Reset,
+        median = format_duration(stats.median),
+        min = format_duration(stats.min),
+        mean = format_duration(stats.mean),
+        stddev = format_duration(stats.stddev),
+        runs = stats.runs,
+    );
+
+    regressed
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::*;
+
+    #[test]
+    fn baseline_test() {
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}", std::process::id()));
+        let path = path.to_str().unwrap();
+        fs::write(
+            path,
+            "# comment\n2025/d1p1/input 1000\n2025/d1p2/example-a b 2000\n2025/d1p1 3\n",
+        )
+        .unwrap();
+
+        let mut baseline = Baseline::load(path);
+        assert_eq!(
+            baseline.get(2025, 1, 1, "input"),
+            Some(Duration::from_nanos(1000))
+        );
+        assert_eq!(baseline.get(2025, 1, 1, "example"), None);
+        assert_eq!(
+            baseline.get(2025, 1, 2, "example-a b"),
+            Some(Duration::from_nanos(2000))
+        );
+
+        baseline.set(2025, 1, 1, "example", Duration::from_nanos(10));
+        baseline.save(path).unwrap();
+        let saved = Baseline::load(path);
+        fs::remove_file(path).unwrap();
+        assert_eq!(
+            saved.get(2025, 1, 1, "input"),
+            Some(Duration::from_nanos(1000))
+        );
+        assert_eq!(
+            saved.get(2025, 1, 1, "example"),
+            Some(Duration::from_nanos(10))
+        );
+    }
+}
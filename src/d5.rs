@@ -1,57 +0,0 @@
-//! A solution to day 5 year 2025.
-//! https://adventofcode.com/2025/day/5
-
-type Model = u8;
-type Answer = String;
-
-pub fn parse(input: String) -> Model {
-    0
-}
-
-pub fn part1(model: Model) -> Answer {
-    "incomplete".to_string()
-}
-
-pub fn part2(model: Model) -> Answer {
-    "incomplete".to_string()
-}
-
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//
-//     const INPUT: &str = include_str!("../input/d5");
-//     const EXAMPLE: &str = include_str!("../examples/d5");
-//
-//     // #[test]
-//     // fn d5p1_example_test() {
-//     //     assert_eq!(
-//     //         part1(parse(EXAMPLE.to_string())),
-//     //         "put part 1 example answer here"
-//     //     );
-//     // }
-//     //
-//     // #[test]
-//     // fn d5p1_input_test() {
-//     //     assert_eq!(
-//     //         part1(parse(INPUT.to_string())),
-//     //         "put part 1 final answer here"
-//     //     );
-//     // }
-//     //
-//     // #[test]
-//     // fn d5p2_example_test() {
-//     //     assert_eq!(
-//     //         part2(parse(EXAMPLE.to_string())),
-//     //         "put part 2 example answer here"
-//     //     );
-//     // }
-//     //
-//     // #[test]
-//     // fn d5p2_input_test() {
-//     //     assert_eq!(
-//     //         part2(parse(INPUT.to_string())),
-//     //         "put part 2 final answer here"
-//     //     );
-//     // }
-// }
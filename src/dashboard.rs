@@ -0,0 +1,498 @@
+//! A full-screen dashboard of a year's days: each part's status, latest answer, and timings,
+//! with keys to run days and to look at what a run printed.
+//!
+//! Every run happens in a child runner (`aoc -d N -f json`), one at a time, so a day's own
+//! output and panics can be shown in the detail pane instead of scribbling over the screen, and
+//! a runaway day can't freeze the dashboard.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    io::{self, Read, Write},
+    process::Command,
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use aoc::{
+    solution::{self, STUB_ANSWER},
+    YEARS,
+};
+use termion::{
+    clear, color, cursor, event::Key, input::TermRead, raw::IntoRawMode,
+    screen::IntoAlternateScreen, style,
+};
+
+use crate::{
+    args::DashboardArgs,
+    output::{format_duration, parse_record, ErrorKind, Record},
+};
+
+/// How many solve times each part's history keeps.
+const HISTORY: usize = 20;
+
+/// How often the screen checks for keys and finished runs.
+const FRAME: Duration = Duration::from_millis(50);
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Which input the days are run on.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+enum Source {
+    Input,
+    Example,
+}
+
+/// Where a part stands after its latest run.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum State {
+    NotRun,
+    Running,
+    /// Still answering with the template's placeholder.
+    Stub,
+    Passing,
+    /// A wrong answer, a panic, or a timeout.
+    Failing,
+    /// An answer that can't be checked, since there's no known answer for it.
+    Unknown,
+    /// The input file isn't there.
+    NoInput,
+}
+
+impl State {
+    fn label(self) -> &'static str {
+        match self {
+            State::NotRun => "not run",
+            State::Running => "running",
+            State::Stub => "stub",
+            State::Passing => "passing",
+            State::Failing => "failing",
+            State::Unknown => "unknown",
+            State::NoInput => "no input",
+        }
+    }
+
+    fn color(self) -> String {
+        match self {
+            State::Passing => color::Fg(color::Green).to_string(),
+            State::Failing => color::Fg(color::Red).to_string(),
+            State::Unknown => color::Fg(color::Yellow).to_string(),
+            State::Running => color::Fg(color::Blue).to_string(),
+            _ => color::Fg(color::LightBlack).to_string(),
+        }
+    }
+}
+
+/// One part's latest result, and how long its runs have taken.
+#[derive(Debug, Clone)]
+struct PartStatus {
+    state: State,
+    answer: Option<String>,
+    error: Option<String>,
+    history: Vec<Duration>,
+}
+
+impl Default for PartStatus {
+    fn default() -> Self {
+        Self {
+            state: State::NotRun,
+            answer: None,
+            error: None,
+            history: vec![],
+        }
+    }
+}
+
+/// Everything a child runner printed for one day.
+#[derive(Debug, Clone, Default)]
+struct DayRun {
+    records: Vec<Record>,
+    /// What the solution printed, without the result records.
+    stdout: Vec<String>,
+    stderr: Vec<String>,
+    /// Why the runner couldn't be started, if it couldn't.
+    error: Option<String>,
+}
+
+struct Dashboard {
+    year: u16,
+    days: Vec<u8>,
+    source: Source,
+    /// The index of the selected day.
+    selected: usize,
+    details: bool,
+    parts: BTreeMap<(Source, u8, u8), PartStatus>,
+    runs: BTreeMap<(Source, u8), DayRun>,
+    /// Days waiting for their run to finish.
+    queued: BTreeSet<(Source, u8)>,
+}
+
+/// Show the dashboard until the user quits.
+pub fn dashboard(args: &DashboardArgs) -> Result<(), String> {
+    let year = solution::find_year(YEARS, args.year).expect("the year is checked when parsing");
+    let exe = env::current_exe().map_err(|e| format!("can't find the runner: {e}"))?;
+    let mut dashboard = Dashboard {
+        year: year.year,
+        days: year.days.iter().map(|d| d.day).collect(),
+        source: Source::Input,
+        selected: 0,
+        details: false,
+        parts: BTreeMap::new(),
+        runs: BTreeMap::new(),
+        queued: BTreeSet::new(),
+    };
+
+    // one worker runs the queued days in order
+    let (jobs, queue) = mpsc::channel::<(Source, u8)>();
+    let (finished, results) = mpsc::channel();
+    let (timeout, year) = (args.timeout, dashboard.year);
+    thread::spawn(move || {
+        for (source, day) in queue {
+            let run = run_day(&exe, year, day, source, timeout);
+            if finished.send((source, day, run)).is_err() {
+                return;
+            }
+        }
+    });
+
+    let err = |e: io::Error| format!("can't set up the terminal: {e}");
+    let mut screen = io::stdout()
+        .into_raw_mode()
+        .map_err(err)?
+        .into_alternate_screen()
+        .map_err(err)?;
+    write!(screen, "{}", cursor::Hide).map_err(err)?;
+    let mut keys = termion::async_stdin().keys();
+
+    let mut redraw = true;
+    loop {
+        if redraw {
+            let (width, height) = termion::terminal_size().unwrap_or((80, 24));
+            write!(screen, "{}", dashboard.render(width, height)).map_err(err)?;
+            screen.flush().map_err(err)?;
+            redraw = false;
+        }
+
+        for (source, day, run) in results.try_iter() {
+            dashboard.finish(source, day, run);
+            redraw = true;
+        }
+
+        while let Some(Ok(key)) = keys.next() {
+            redraw = true;
+            let day = dashboard.days.get(dashboard.selected).copied();
+            match key {
+                Key::Char('q') | Key::Esc | Key::Ctrl('c') => {
+                    write!(screen, "{}", cursor::Show).map_err(err)?;
+                    return Ok(());
+                }
+                Key::Up | Key::Char('k') => {
+                    dashboard.selected = dashboard.selected.saturating_sub(1)
+                }
+                Key::Down | Key::Char('j') => {
+                    dashboard.selected = (dashboard.selected + 1).min(dashboard.days.len() - 1)
+                }
+                Key::Char('\n') | Key::Char('r') => {
+                    if let Some(day) = day {
+                        dashboard.queue(&jobs, day);
+                    }
+                }
+                Key::Char('a') => {
+                    for day in dashboard.days.clone() {
+                        dashboard.queue(&jobs, day);
+                    }
+                }
+                Key::Char('e') => {
+                    dashboard.source = match dashboard.source {
+                        Source::Input => Source::Example,
+                        Source::Example => Source::Input,
+                    }
+                }
+                Key::Char('d') | Key::Char('\t') => dashboard.details = !dashboard.details,
+                _ => redraw = false,
+            }
+        }
+
+        thread::sleep(FRAME);
+    }
+}
+
+impl Dashboard {
+    /// Queue a run of a day on the current source, unless one is already waiting.
+    fn queue(&mut self, jobs: &mpsc::Sender<(Source, u8)>, day: u8) {
+        let job = (self.source, day);
+        if self.queued.insert(job) && jobs.send(job).is_ok() {
+            for part in [1, 2] {
+                self.parts.entry((job.0, day, part)).or_default().state = State::Running;
+            }
+        }
+    }
+
+    /// Record a finished run.
+    fn finish(&mut self, source: Source, day: u8, run: DayRun) {
+        self.queued.remove(&(source, day));
+
+        for part in [1, 2] {
+            let status = self.parts.entry((source, day, part)).or_default();
+            let record = run.records.iter().find(|r| r.part == part);
+            status.answer = record.and_then(|r| r.answer.clone());
+            status.error = record.and_then(|r| r.message.clone());
+            if let Some(solve) = record.and_then(|r| r.solve) {
+                status.history.push(solve);
+                if status.history.len() > HISTORY {
+                    status.history.remove(0);
+                }
+            }
+
+            status.state = match record {
+                Some(record) if record.error == Some(ErrorKind::NoInput) => State::NoInput,
+                None => State::Failing,
+                Some(record) if record.error.is_some() => State::Failing,
+                Some(record) if record.answer.as_deref() == Some(STUB_ANSWER) => State::Stub,
+                Some(record) => match record.status.as_deref() {
+                    Some("correct") => State::Passing,
+                    Some("wrong") => State::Failing,
+                    _ => State::Unknown,
+                },
+            };
+        }
+
+        self.runs.insert((source, day), run);
+    }
+
+    /// Draw the whole screen.
+    fn render(&self, width: u16, height: u16) -> String {
+        let mut lines = vec![];
+        let source = match self.source {
+            Source::Input => "puzzle inputs",
+            Source::Example => "examples",
+        };
+        lines.push(format!(
+            "{}Advent of Code {}{}, on the {source}",
+            style::Bold,
+            self.year,
+            style::Reset
+        ));
+        lines.push(String::new());
+        lines.push(format!(
+            "{}{:>4} {:>4}  {:<9} {:<24} {:>10}  history{}",
+            style::Underline,
+            "day",
+            "part",
+            "status",
+            "answer",
+            "time",
+            style::Reset
+        ));
+
+        // the detail pane takes the bottom half of the screen
+        let footer = 2;
+        let body = (height as usize).saturating_sub(lines.len() + footer);
+        let (list, pane) = if self.details {
+            (body / 2, body - body / 2)
+        } else {
+            (body, 0)
+        };
+
+        // scroll to keep the selected day in view, two rows per day
+        let shown = (list / 2).max(1);
+        let first = self.selected.saturating_sub(shown - 1);
+        for (i, &day) in self.days.iter().enumerate().skip(first).take(shown) {
+            for part in [1, 2] {
+                lines.push(self.row(day, part, i == self.selected));
+            }
+        }
+        while lines.len() < 3 + list {
+            lines.push(String::new());
+        }
+
+        if self.details {
+            lines.extend(self.details(pane, width as usize));
+        }
+        while lines.len() < (height as usize).saturating_sub(1) {
+            lines.push(String::new());
+        }
+        lines.push(format!(
+            "{}↑/↓ select  enter run  a run all  e {}  d details  q quit{}",
+            color::Fg(color::LightBlack),
+            match self.source {
+                Source::Input => "examples",
+                Source::Example => "inputs",
+            },
+            style::Reset
+        ));
+
+        let mut screen = String::new();
+        for (row, line) in lines.iter().take(height as usize).enumerate() {
+            screen.push_str(&format!(
+                "{}{line}{}",
+                cursor::Goto(1, row as u16 + 1),
+                clear::UntilNewline
+            ));
+        }
+        screen
+    }
+
+    /// One part's row in the list.
+    fn row(&self, day: u8, part: u8, selected: bool) -> String {
+        let status = self
+            .parts
+            .get(&(self.source, day, part))
+            .cloned()
+            .unwrap_or_default();
+        let answer = match (&status.answer, &status.error) {
+            (Some(answer), _) => answer.lines().next().unwrap_or_default().to_string(),
+            (None, Some(error)) => error.clone(),
+            (None, None) => String::new(),
+        };
+        let time = status
+            .history
+            .last()
+            .map(|t| format_duration(*t))
+            .unwrap_or_default();
+        let day_label = if part == 1 {
+            day.to_string()
+        } else {
+            String::new()
+        };
+
+        format!(
+            "{invert}{day_label:>4} {part:>4}  {color}{state:<9}{reset}{invert} {answer:<24} {time:>10}  {history}{reset}",
+            invert = if selected { style::Invert.to_string() } else { String::new() },
+            color = status.state.color(),
+            state = status.state.label(),
+            reset = style::Reset,
+            answer = truncate(&answer, 24),
+            history = sparkline(&status.history),
+        )
+    }
+
+    /// The selected day's last run: each part's answer or failure, then what the run printed.
+    fn details(&self, height: usize, width: usize) -> Vec<String> {
+        let Some(&day) = self.days.get(self.selected) else {
+            return vec![];
+        };
+        let mut lines = vec![format!(
+            "{}day {day}'s last run{}",
+            style::Underline,
+            style::Reset
+        )];
+
+        match self.runs.get(&(self.source, day)) {
+            None => lines.push("not run yet, press enter to run it".to_string()),
+            Some(run) => {
+                if let Some(error) = &run.error {
+                    lines.push(format!("{}{error}{}", color::Fg(color::Red), style::Reset));
+                }
+                for record in &run.records {
+                    match (&record.answer, &record.message) {
+                        (_, Some(error)) => lines.push(format!(
+                            "p{}: {}{error}{}",
+                            record.part,
+                            color::Fg(color::Red),
+                            style::Reset
+                        )),
+                        (Some(answer), None) if answer.contains('\n') => {
+                            lines.push(format!("p{}:", record.part));
+                            lines.extend(answer.lines().map(|l| format!("  {l}")));
+                        }
+                        (Some(answer), None) => lines.push(format!("p{}: {answer}", record.part)),
+                        (None, None) => {}
+                    }
+                }
+                if !run.stdout.is_empty() {
+                    lines.push("stdout:".to_string());
+                    lines.extend(
+                        run.stdout
+                            .iter()
+                            .map(|l| format!("  {}", truncate(l, width))),
+                    );
+                }
+                if !run.stderr.is_empty() {
+                    lines.push("stderr:".to_string());
+                    lines.extend(
+                        run.stderr
+                            .iter()
+                            .map(|l| format!("  {}", truncate(l, width))),
+                    );
+                }
+            }
+        }
+
+        lines.truncate(height);
+        lines
+    }
+}
+
+/// Run one day in a child runner, and collect what it printed.
+fn run_day(exe: &std::path::Path, year: u16, day: u8, source: Source, timeout: Duration) -> DayRun {
+    let mut command = Command::new(exe);
+    command
+        .args(["-y", &year.to_string(), "-d", &day.to_string()])
+        .args(["-f", "json", "-t", &format!("{}ms", timeout.as_millis())]);
+    if source == Source::Example {
+        command.arg("-e");
+    }
+
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(e) => {
+            return DayRun {
+                error: Some(format!("can't run the runner: {e}")),
+                ..DayRun::default()
+            }
+        }
+    };
+
+    let mut run = DayRun::default();
+    let mut stdout = String::new();
+    let _ = output.stdout.as_slice().read_to_string(&mut stdout);
+    for line in stdout.lines() {
+        match parse_record(line) {
+            Some(record) => run.records.push(record),
+            None => run.stdout.push(line.to_string()),
+        }
+    }
+    run.stderr = String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .map(String::from)
+        .collect();
+    run
+}
+
+/// Shorten s to at most width characters, marking where it was cut.
+fn truncate(s: &str, width: usize) -> String {
+    if s.chars().count() <= width {
+        s.to_string()
+    } else {
+        let mut cut: String = s.chars().take(width.saturating_sub(1)).collect();
+        cut.push('…');
+        cut
+    }
+}
+
+/// A bar per duration, scaled between the shortest and the longest.
+fn sparkline(history: &[Duration]) -> String {
+    let (Some(min), Some(max)) = (history.iter().min(), history.iter().max()) else {
+        return String::new();
+    };
+    let range = (*max - *min).as_nanos().max(1);
+    history
+        .iter()
+        .map(|t| SPARKS[((*t - *min).as_nanos() * (SPARKS.len() as u128 - 1) / range) as usize])
+        .collect()
+}
+
+#[cfg(test)]
+mod dashboard_tests {
+    use super::*;
+
+    #[test]
+    fn sparkline_test() {
+        let history = [3, 1, 2, 5].map(Duration::from_millis);
+        assert_eq!(sparkline(&history), "▄▁▂█");
+        assert_eq!(sparkline(&[Duration::from_millis(4)]), "▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+}
@@ -19,6 +19,16 @@ impl CardDir {
             Left => Up,
         }
     }
+
+    pub fn ccw(&self) -> CardDir {
+        use CardDir::*;
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up,
+        }
+    }
 }
 
 impl From<char> for CardDir {
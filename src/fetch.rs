@@ -0,0 +1,242 @@
+//! Downloading puzzle inputs from the Advent of Code site.
+//!
+//! The site is `AOC_BASE_URL` if it's set, so a local stand-in server can take the real site's
+//! place.  Requests are logged in with the `AOC_SESSION` cookie.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::http::{self, Http, Response};
+
+/// The real Advent of Code site.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Where the time of the latest request is kept, so separate runs share one rate limit.
+pub const STAMP_FILE: &str = "input/.last_request";
+
+/// The least time allowed between requests to the site.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The site asks automated tools to say where they come from.
+const USER_AGENT: &str = "github.com/mwcz/advent-of-code-2025 runner";
+
+/// Where to send requests, and how.
+pub struct Site {
+    pub base_url: String,
+    /// The `session` cookie's value.
+    pub session: Option<String>,
+    pub http: Box<dyn Http>,
+    pub stamp_file: PathBuf,
+    pub min_interval: Duration,
+}
+
+impl Site {
+    /// The site in `AOC_BASE_URL` (the real one by default), logged in with `AOC_SESSION`.
+    pub fn from_env() -> Self {
+        let base_url = std::env::var("AOC_BASE_URL")
+            .ok()
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+
+        Self {
+            http: http::client_for(&base_url),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: std::env::var("AOC_SESSION").ok().filter(|s| !s.is_empty()),
+            stamp_file: PathBuf::from(STAMP_FILE),
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    /// Make a request to a path on the site, first waiting out the rate limit.  A body is sent
+    /// as form data.
+    pub fn request(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&str>,
+    ) -> Result<Response, String> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or("please set AOC_SESSION to your session cookie")?;
+        let cookie = format!("session={session}");
+        let mut headers = vec![("Cookie", cookie.as_str()), ("User-Agent", USER_AGENT)];
+        if body.is_some() {
+            headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+        }
+
+        self.wait_for_turn()?;
+        let url = format!("{}{path}", self.base_url);
+        self.http.request(method, &url, &headers, body)
+    }
+
+    /// Sleep until min_interval has passed since the latest request, then record this one.
+    fn wait_for_turn(&self) -> Result<(), String> {
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+        };
+
+        let last = fs::read_to_string(&self.stamp_file)
+            .ok()
+            .and_then(|stamp| stamp.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(last) = last {
+            let elapsed = now().saturating_sub(last);
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        if let Some(dir) = self.stamp_file.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("can't create {}: {e}", dir.display()))?;
+        }
+        fs::write(&self.stamp_file, now().as_millis().to_string())
+            .map_err(|e| format!("can't write {}: {e}", self.stamp_file.display()))
+    }
+}
+
+/// Where an input came from.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Fetched {
+    /// The file was already there, so nothing was downloaded.
+    Cached,
+    Downloaded,
+}
+
+/// Download a day's input to path, unless it's already there.  Inputs never change, so a cached
+/// input is never downloaded again.
+pub fn fetch_input(
+    site: &Site,
+    year: u16,
+    day: u8,
+    path: impl AsRef<Path>,
+) -> Result<Fetched, String> {
+    let path = path.as_ref();
+    if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let response = site.request("GET", &format!("/{year}/day/{day}/input"), None)?;
+    let input = check_input(response)?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("can't create {}: {e}", dir.display()))?;
+    }
+    fs::write(path, input).map_err(|e| format!("can't write {}: {e}", path.display()))?;
+
+    Ok(Fetched::Downloaded)
+}
+
+/// Make sure a response holds a puzzle input, and not an error or a login page.
+fn check_input(response: Response) -> Result<String, String> {
+    let body = response.body;
+    if body.contains("Puzzle inputs differ by user") {
+        return Err("not logged in, check that AOC_SESSION is current".to_string());
+    }
+
+    match response.status {
+        200 => {}
+        404 => return Err("that day isn't unlocked yet".to_string()),
+        status => {
+            return Err(format!(
+                "unexpected HTTP status {status}: {}",
+                body.lines().next().unwrap_or_default()
+            ))
+        }
+    }
+
+    let trimmed = body.trim_start();
+    if trimmed.starts_with('<') || body.contains("<html") {
+        return Err("got a web page instead of a puzzle input".to_string());
+    }
+    if trimmed.is_empty() {
+        return Err("the input is empty".to_string());
+    }
+
+    Ok(body)
+}
+
+#[cfg(test)]
+mod fetch_tests {
+    use super::*;
+    use crate::http::{stand_in, TcpHttp};
+
+    /// A scratch directory for one test.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn site(base_url: String, dir: &Path) -> Site {
+        Site {
+            base_url,
+            session: Some("abc123".to_string()),
+            http: Box::new(TcpHttp),
+            stamp_file: dir.join(".last_request"),
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn fetch_input_test() {
+        let dir = scratch("download");
+        let (base_url, requests) = stand_in::serve(vec![(200, "1 2 3\n")]);
+        let site = site(base_url, &dir);
+        let path = dir.join("2025/d1");
+
+        assert_eq!(fetch_input(&site, 2025, 1, &path), Ok(Fetched::Downloaded));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        assert_eq!(fetch_input(&site, 2025, 1, &path), Ok(Fetched::Cached));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/1/input "));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+        assert!(dir.join(".last_request").exists());
+    }
+
+    #[test]
+    fn bad_response_test() {
+        let dir = scratch("bad");
+        let (base_url, _) = stand_in::serve(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (200, "<!DOCTYPE html>\n<html lang=\"en-us\">"),
+        ]);
+        let site = site(base_url, &dir);
+        let path = dir.join("d1");
+
+        for _ in 0..3 {
+            assert!(fetch_input(&site, 2025, 1, &path).is_err());
+            assert!(!path.exists());
+        }
+    }
+
+    #[test]
+    fn rate_limit_test() {
+        let dir = scratch("rate");
+        let site = Site {
+            min_interval: Duration::from_millis(200),
+            ..site(String::new(), &dir)
+        };
+
+        site.wait_for_turn().unwrap();
+        let t = std::time::Instant::now();
+        site.wait_for_turn().unwrap();
+        assert!(t.elapsed() >= Duration::from_millis(150));
+    }
+}
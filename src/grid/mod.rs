@@ -0,0 +1,846 @@
+use std::{fmt::Display, iter::StepBy, slice, str::FromStr};
+
+use crate::{direction::CardDir, point::Point};
+
+pub mod regions;
+pub mod search;
+
+/// A rectangular grid, stored row by row in one contiguous `Vec`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid<T: Copy> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// The cells of one column, top to bottom.  Produced by Grid::col and Grid::cols.
+pub type Col<'a, T> = StepBy<slice::Iter<'a, T>>;
+
+impl<T: Copy> Grid<T> {
+    /// Create a grid from its rows.  Panics if the rows aren't all the same length.
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "every row of a grid must be the same length"
+        );
+        Self {
+            height: rows.len(),
+            cells: rows.concat(),
+            width,
+        }
+    }
+
+    /// Create a grid from its cells, row by row.  Panics if there aren't `width * height` cells.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height);
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    /// All the cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// The position of (x, y) in `cells`, or None if it's outside the grid.
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// The cells of one row.  Panics if the row is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The cells of one column, top to bottom.  Panics if the column is outside the grid.
+    pub fn col(&self, x: usize) -> Col<'_, T> {
+        assert!(x < self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Every column, left to right.
+    pub fn cols(&self) -> impl ExactSizeIterator<Item = Col<'_, T>> + '_ {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<T> {
+        self.index(x, y).and_then(|i| self.cells.get(i)).copied()
+    }
+
+    pub fn getp(&self, p: Point<2>) -> Option<T> {
+        self.get(p.x() as usize, p.y() as usize)
+    }
+
+    /// Copy the items from new_col into the grid at the given column index (col_idx).
+    pub fn set_col(&mut self, col_idx: usize, new_col: &[T]) {
+        assert_eq!(new_col.len(), self.height());
+        assert!(col_idx < self.width);
+
+        for (cell, t) in self.cells[col_idx..]
+            .iter_mut()
+            .step_by(self.width)
+            .zip(new_col)
+        {
+            *cell = *t;
+        }
+    }
+
+    /// Copy the items from new_row into the grid at the given row index (row_idx).
+    pub fn set_row(&mut self, row_idx: usize, new_row: &[T]) {
+        assert_eq!(new_row.len(), self.width());
+        assert!(row_idx < self.height);
+
+        let start = row_idx * self.width;
+        self.cells[start..start + self.width].copy_from_slice(new_row);
+    }
+
+    /// Set data in the grid.  Panics if the coordinates are out of bounds.
+    pub fn set(&mut self, x: usize, y: usize, new_data: T) {
+        assert!(y < self.height);
+        assert!(x < self.width);
+        self.cells[y * self.width + x] = new_data;
+    }
+
+    /// Set data in the grid using a Point as coordinates.  Panics if the coordinates are out of bounds.
+    pub fn setp(&mut self, p: Point<2>, new_data: T) {
+        assert!(p.x() > 0);
+        assert!(p.y() > 0);
+        self.set(p.x() as usize, p.y() as usize, new_data);
+    }
+
+    /// Get cells adjacent to the given point in the cardinal directions.  Origin is up-left from
+    /// the given point.  Cells outside the grid bounds will be None.
+    ///
+    /// # Ordering
+    ///
+    /// Four cells will always be returned, in the following order relative to the given point:
+    ///  
+    /// ```
+    /// [ ⬆️, ⬅️, ➡️, ⬇️, ]
+    /// ```
+    ///
+    /// In words: up left, up, up right, left, right, down left, down, down right.
+    pub fn adj_4(&self, loc: Point<2>) -> Adj4<T> {
+        Adj4::new(
+            [
+                (Some(loc.x()), loc.y().checked_sub(1)),
+                (loc.x().checked_sub(1), Some(loc.y())),
+                (loc.x().checked_add(1), Some(loc.y())),
+                (Some(loc.x()), loc.y().checked_add(1)),
+            ]
+            .map(|(adj_x, adj_y)| {
+                adj_x.and_then(|adj_x| {
+                    adj_y.and_then(|adj_y| {
+                        self.get(adj_x as usize, adj_y as usize)
+                            .map(|cell_data| Cell::new([adj_x, adj_y].into(), cell_data))
+                    })
+                })
+            }),
+        )
+    }
+
+    /// Get cells adjacent to the given point in cardinal and ordinal directions (ie,
+    /// up/down/left/right and diagonals).  Origin is up-left from the given point.  Cells outside
+    /// the grid bounds will be None.
+    ///
+    /// # Ordering
+    ///
+    /// Eight cells will always be returned, in the following order relative to the given point:
+    ///  
+    /// ```
+    /// [
+    ///   ↖️, ⬆️, ↗️,
+    ///   ⬅️,    ➡️,
+    ///   ↙️, ⬇️, ↘️,
+    /// ]
+    /// ```
+    ///
+    /// In words: up left, up, up right, left, right, down left, down, down right.
+    pub fn adj_8(&self, x: usize, y: usize) -> Adj8<T> {
+        Adj8::new(
+            [
+                (x.checked_sub(1), y.checked_sub(1)),
+                (Some(x), y.checked_sub(1)),
+                (x.checked_add(1), y.checked_sub(1)),
+                (x.checked_sub(1), Some(y)),
+                (x.checked_add(1), Some(y)),
+                (x.checked_sub(1), y.checked_add(1)),
+                (Some(x), y.checked_add(1)),
+                (x.checked_add(1), y.checked_add(1)),
+            ]
+            .map(|(adj_x, adj_y)| {
+                adj_x.and_then(|adj_x| {
+                    adj_y.and_then(|adj_y| {
+                        self.get(adj_x, adj_y)
+                            .map(|cell_data| Cell::new([adj_x, adj_y].into(), cell_data))
+                    })
+                })
+            }),
+        )
+    }
+}
+
+/// Why puzzle text couldn't be read as a grid.  Lines and columns count from 1.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseGridError {
+    /// A line isn't as wide as the first one.
+    Ragged {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// A character that can't be a cell of this kind of grid.
+    BadCell { line: usize, column: usize, c: char },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::Ragged {
+                line,
+                width,
+                expected,
+            } => write!(
+                f,
+                "line {line} is {width} cells wide, expected {expected} like line 1"
+            ),
+            ParseGridError::BadCell { line, column, c } => {
+                write!(f, "line {line}, column {column}: {c:?} isn't a valid cell")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl<T: Copy> Grid<T> {
+    /// Read a grid from puzzle text, one line per row and one character per cell, mapping each
+    /// character to a cell with `f`.  Blank lines at the end are ignored.
+    pub fn from_str_with(
+        input: &str,
+        mut f: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseGridError> {
+        Self::parse(input, "", |c| Some(f(c))).map(|(grid, _)| grid)
+    }
+
+    /// Like from_str_with, but also return where each of the `markers` characters (like `S` and
+    /// `E` for start and end) was found, in reading order.  Markers are still mapped with `f`, so
+    /// it decides what's underneath them.
+    pub fn from_str_with_markers(
+        input: &str,
+        markers: &str,
+        mut f: impl FnMut(char) -> T,
+    ) -> Result<(Self, Vec<Cell<char>>), ParseGridError> {
+        Self::parse(input, markers, |c| Some(f(c)))
+    }
+
+    /// Read a grid from puzzle text, rejecting characters `f` returns None for.
+    fn parse(
+        input: &str,
+        markers: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Self, Vec<Cell<char>>), ParseGridError> {
+        let mut cells = vec![];
+        let mut found = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let mut x = 0;
+            for c in line.chars() {
+                if markers.contains(c) {
+                    found.push(Cell::new([x, y].into(), c));
+                }
+                cells.push(f(c).ok_or(ParseGridError::BadCell {
+                    line: y + 1,
+                    column: x + 1,
+                    c,
+                })?);
+                x += 1;
+            }
+
+            match width {
+                None => width = Some(x),
+                Some(expected) if x != expected => {
+                    return Err(ParseGridError::Ragged {
+                        line: y + 1,
+                        width: x,
+                        expected,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        let grid = Self::from_cells(width.unwrap_or(0), height, cells);
+        Ok((grid, found))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(s, |c| c)
+    }
+}
+
+/// Each cell is the character's ASCII byte.
+impl FromStr for Grid<u8> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, "", |c| c.is_ascii().then_some(c as u8)).map(|(grid, _)| grid)
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    /// Build a width x height grid, taking each cell (x, y) from this grid's cell at source(x, y).
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let (source_x, source_y) = source(x, y);
+                cells.push(self.cells[source_y * self.width + source_x]);
+            }
+        }
+        Self::from_cells(width, height, cells)
+    }
+
+    /// Swap rows and columns, mirroring the grid along the diagonal from the top left.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotate the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let h = self.height;
+        self.remap(self.height, self.width, |x, y| (y, h - 1 - x))
+    }
+
+    /// Rotate the grid a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        let w = self.width;
+        self.remap(self.height, self.width, |x, y| (w - 1 - y, x))
+    }
+
+    /// Mirror the grid left to right.
+    pub fn flip_h(&self) -> Self {
+        let w = self.width;
+        self.remap(self.width, self.height, |x, y| (w - 1 - x, y))
+    }
+
+    /// Mirror the grid top to bottom.
+    pub fn flip_v(&self) -> Self {
+        let h = self.height;
+        self.remap(self.width, self.height, |x, y| (x, h - 1 - y))
+    }
+
+    /// All 8 ways the grid can be turned and flipped: the grid rotated clockwise 0, 1, 2, and 3
+    /// quarter turns, then the same for the grid flipped left to right.  Symmetric grids will have
+    /// duplicates.
+    pub fn orientations(&self) -> [Self; 8] {
+        let turns = |grid: Self| {
+            let cw = grid.rotate_cw();
+            let half = cw.rotate_cw();
+            let ccw = half.rotate_cw();
+            [grid, cw, half, ccw]
+        };
+        let [a, b, c, d] = turns(self.clone());
+        let [e, f, g, h] = turns(self.flip_h());
+        [a, b, c, d, e, f, g, h]
+    }
+
+    /// A width x height window onto the grid with its top left at (x, y), borrowing the cells
+    /// instead of copying them.  Panics if the window doesn't fit in the grid.
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> View<'_, T> {
+        assert!(x + width <= self.width);
+        assert!(y + height <= self.height);
+        View {
+            grid: self,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: Copy + PartialEq> Grid<T> {
+    pub fn match_kernel<const D: usize>(&self, kernel: [[Option<T>; D]; D], pos: Point<2>) -> bool {
+        for ky in 0..D {
+            for kx in 0..D {
+                if let Some(k) = kernel[ky][kx] {
+                    if self
+                        .get(pos.x() as usize + kx, pos.y() as usize + ky)
+                        .and_then(|c| (c == k).then_some(()))
+                        .is_none()
+                    {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Display + Copy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A rectangular part of a grid, borrowed from it.  Produced by Grid::view.  Coordinates are
+/// relative to the view's top left.
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T: Copy> {
+    grid: &'a Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T: Copy> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<T> {
+        (x < self.width && y < self.height)
+            .then(|| self.grid.get(self.x + x, self.y + y))
+            .flatten()
+    }
+
+    pub fn getp(&self, p: Point<2>) -> Option<T> {
+        self.get(p.x() as usize, p.y() as usize)
+    }
+
+    /// The cells of one of the view's rows.  Panics if the row is outside the view.
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height);
+        &self.grid.row(self.y + y)[self.x..self.x + self.width]
+    }
+
+    /// The cells of one of the view's columns, top to bottom.  Panics if the column is outside
+    /// the view.
+    pub fn col(&self, x: usize) -> impl ExactSizeIterator<Item = &'a T> {
+        assert!(x < self.width);
+        self.grid.col(self.x + x).skip(self.y).take(self.height)
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Every column, left to right.
+    pub fn cols(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &'a T>> + '_ {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    /// Copy the view's cells into a grid of their own.
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_cells(
+            self.width,
+            self.height,
+            self.rows().flatten().copied().collect(),
+        )
+    }
+}
+
+impl<T> Display for View<'_, T>
+where
+    T: Display + Copy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A representation of cells adjacent to a point in the cardinal directions.  Produced by Grid::adj_4.
+#[derive(PartialEq, Debug)]
+pub struct Adj4<T: Copy> {
+    pub cells: [Option<Cell<T>>; 4],
+}
+
+impl<T: Copy> Adj4<T> {
+    pub fn new(cells: [Option<Cell<T>>; 4]) -> Self {
+        Self { cells }
+    }
+    pub fn up(&self) -> Option<Cell<T>> {
+        self.cells[0]
+    }
+    pub fn left(&self) -> Option<Cell<T>> {
+        self.cells[1]
+    }
+    pub fn right(&self) -> Option<Cell<T>> {
+        self.cells[2]
+    }
+    pub fn down(&self) -> Option<Cell<T>> {
+        self.cells[3]
+    }
+
+    /// The cell in the given direction.
+    pub fn toward(&self, dir: CardDir) -> Option<Cell<T>> {
+        match dir {
+            CardDir::Up => self.up(),
+            CardDir::Left => self.left(),
+            CardDir::Right => self.right(),
+            CardDir::Down => self.down(),
+        }
+    }
+}
+
+/// A representation of cells adjacent to a point.  Produced by Grid::adj_8.
+#[derive(PartialEq, Debug)]
+pub struct Adj8<T: Copy> {
+    pub cells: [Option<Cell<T>>; 8],
+}
+
+impl<T: Copy> Adj8<T> {
+    pub fn new(cells: [Option<Cell<T>>; 8]) -> Self {
+        Self { cells }
+    }
+
+    pub fn up_left(&self) -> Option<Cell<T>> {
+        self.cells[0]
+    }
+    pub fn up(&self) -> Option<Cell<T>> {
+        self.cells[1]
+    }
+    pub fn up_right(&self) -> Option<Cell<T>> {
+        self.cells[2]
+    }
+    pub fn left(&self) -> Option<Cell<T>> {
+        self.cells[3]
+    }
+    pub fn right(&self) -> Option<Cell<T>> {
+        self.cells[4]
+    }
+    pub fn down_left(&self) -> Option<Cell<T>> {
+        self.cells[5]
+    }
+    pub fn down(&self) -> Option<Cell<T>> {
+        self.cells[6]
+    }
+    pub fn down_right(&self) -> Option<Cell<T>> {
+        self.cells[7]
+    }
+}
+
+/// A cell in a grid, containing some data and a position within the grid.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Cell<T> {
+    pub pos: Point<2>,
+    pub data: T,
+}
+
+impl<T> Cell<T> {
+    pub fn new(pos: Point<2>, data: T) -> Self {
+        Self { pos, data }
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    #[test]
+    fn empty_test() {
+        let g: Grid<bool> = Grid::new(vec![]);
+        assert_eq!(
+            g.adj_8(0, 0),
+            Adj8::new([None, None, None, None, None, None, None, None])
+        );
+        assert_eq!(
+            g.adj_8(1, 1),
+            Adj8::new([None, None, None, None, None, None, None, None])
+        );
+    }
+
+    #[test]
+    fn one_row_test() {
+        let g: Grid<u8> = Grid::new(vec![vec![1, 2, 3, 4, 5, 6, 7]]);
+        #[rustfmt::skip]
+        assert_eq!(
+            g.adj_8(0, 0),
+            Adj8::new([
+                None,                              None,                              None,
+                None,                                                                 Some(Cell::new([1,0].into(), 2)),
+                None,                              None,                              None,
+            ])
+        );
+        #[rustfmt::skip]
+        assert_eq!(
+            g.adj_8(3, 0),
+            Adj8::new([
+                None,                              None,                              None,
+                Some(Cell::new([2,0].into(), 3)),                                     Some(Cell::new([4,0].into(), 5)),
+                None,                              None,                              None,
+            ])
+        );
+    }
+
+    #[test]
+    fn grid_3x3_test() {
+        #[rustfmt::skip]
+        let g: Grid<u8> = Grid::new(
+            vec![
+                vec![1, 2, 3],
+                vec![4, 5, 6],
+                vec![7, 8, 9]
+            ],
+        );
+        #[rustfmt::skip]
+        assert_eq!(
+            g.adj_8(0, 0),
+            Adj8::new([
+                None,                              None,                              None,
+                None,                                                                 Some(Cell::new([1,0].into(), 2)),
+                None,                              Some(Cell::new([0, 1].into(), 4)), Some(Cell::new([1,1].into(), 5)),
+            ])
+        );
+        #[rustfmt::skip]
+        assert_eq!(
+            g.adj_8(1, 1),
+            Adj8::new([
+                Some(Cell::new([0, 0].into(), 1)), Some(Cell::new([1, 0].into(), 2)), Some(Cell::new([2,0].into(), 3)),
+                Some(Cell::new([0, 1].into(), 4)),                                    Some(Cell::new([2,1].into(), 6)),
+                Some(Cell::new([0, 2].into(), 7)), Some(Cell::new([1, 2].into(), 8)), Some(Cell::new([2,2].into(), 9)),
+            ])
+        );
+    }
+
+    #[test]
+    fn grid_set_col_test() {
+        #[rustfmt::skip]
+        let mut g: Grid<u8> = Grid::new(
+            vec![
+                vec![1, 2, 3],
+                vec![4, 5, 6],
+                vec![7, 8, 9]
+            ],
+        );
+
+        g.set_col(1, &[13, 11, 12]);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            g,
+            Grid::new(vec![
+                vec![1, 13, 3],
+                vec![4, 11, 6],
+                vec![7, 12, 9]
+            ])
+        );
+    }
+
+    #[test]
+    fn grid_set_row_test() {
+        #[rustfmt::skip]
+        let mut g: Grid<u8> = Grid::new(
+            vec![
+                vec![1, 2, 3],
+                vec![4, 5, 6],
+                vec![7, 8, 9]
+            ],
+        );
+
+        g.set_row(1, &[13, 11, 12]);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            g,
+            Grid::new(vec![
+                vec![ 1,  2,  3],
+                vec![13, 11, 12],
+                vec![ 7,  8,  9]
+            ])
+        );
+    }
+
+    #[test]
+    fn rows_cols_test() {
+        let g = Grid::from_cells(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(g.rows().collect::<Vec<_>>(), vec![&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(
+            g.cols()
+                .map(|col| col.copied().collect())
+                .collect::<Vec<Vec<u8>>>(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(g.get(2, 1), Some(6));
+        assert_eq!(g.get(3, 0), None);
+        assert_eq!(g.getp([-1, 0].into()), None);
+        assert_eq!(g.to_string(), "123\n456\n");
+
+        let empty: Grid<u8> = Grid::new(vec![]);
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.cols().count(), 0);
+    }
+
+    #[test]
+    fn from_str_test() {
+        let g: Grid<char> = "#.S\n..#\n\n".parse().unwrap();
+        assert_eq!(g, Grid::new(vec![vec!['#', '.', 'S'], vec!['.', '.', '#']]));
+
+        let g: Grid<u8> = "12\n34\n".parse().unwrap();
+        assert_eq!(g.cells(), b"1234");
+        assert_eq!(
+            "ab\ncé\n".parse::<Grid<u8>>(),
+            Err(ParseGridError::BadCell {
+                line: 2,
+                column: 2,
+                c: 'é'
+            })
+        );
+
+        let g = Grid::from_str_with("19\n28", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(g.get(1, 0), Some(9));
+    }
+
+    #[test]
+    fn from_str_ragged_test() {
+        let e = Grid::from_str_with("...\n..\n...", |c| c == '#').unwrap_err();
+        assert_eq!(
+            e,
+            ParseGridError::Ragged {
+                line: 2,
+                width: 2,
+                expected: 3
+            }
+        );
+        assert_eq!(
+            e.to_string(),
+            "line 2 is 2 cells wide, expected 3 like line 1"
+        );
+    }
+
+    #[test]
+    fn from_str_markers_test() {
+        let (g, markers) = Grid::from_str_with_markers("S.#\n.#E\n", "SE", |c| c == '#').unwrap();
+        assert_eq!(g.get(0, 0), Some(false));
+        assert_eq!(g.get(1, 1), Some(true));
+        assert_eq!(
+            markers,
+            vec![Cell::new([0, 0].into(), 'S'), Cell::new([2, 1].into(), 'E')]
+        );
+    }
+
+    #[test]
+    fn transform_test() {
+        let g: Grid<char> = "123\n456\n".parse().unwrap();
+        let rows = |g: &Grid<char>| g.to_string();
+
+        assert_eq!(rows(&g.transpose()), "14\n25\n36\n");
+        assert_eq!(rows(&g.rotate_cw()), "41\n52\n63\n");
+        assert_eq!(rows(&g.rotate_ccw()), "36\n25\n14\n");
+        assert_eq!(rows(&g.flip_h()), "321\n654\n");
+        assert_eq!(rows(&g.flip_v()), "456\n123\n");
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(g.transpose().transpose(), g);
+
+        let empty: Grid<char> = Grid::new(vec![]);
+        assert_eq!(empty.rotate_cw(), empty);
+    }
+
+    #[test]
+    fn orientations_test() {
+        let g: Grid<char> = "12\n34\n".parse().unwrap();
+        let all: Vec<String> = g.orientations().iter().map(|g| g.to_string()).collect();
+        assert_eq!(
+            all,
+            vec![
+                "12\n34\n", "31\n42\n", "43\n21\n", "24\n13\n", "21\n43\n", "42\n31\n", "34\n12\n",
+                "13\n24\n",
+            ]
+        );
+
+        // a symmetric grid looks the same every way up
+        let plus: Grid<char> = ".#.\n###\n.#.\n".parse().unwrap();
+        assert!(plus.orientations().iter().all(|o| *o == plus));
+    }
+
+    #[test]
+    fn view_test() {
+        #[rustfmt::skip]
+        let g: Grid<u8> = Grid::new(
+            vec![
+                vec![1, 2, 3],
+                vec![4, 5, 6],
+                vec![7, 8, 9]
+            ],
+        );
+        let v = g.view(1, 1, 2, 2);
+        assert_eq!((v.width(), v.height()), (2, 2));
+        assert_eq!(v.get(0, 0), Some(5));
+        assert_eq!(v.get(1, 1), Some(9));
+        assert_eq!(v.get(2, 0), None);
+        assert_eq!(v.rows().collect::<Vec<_>>(), vec![&[5, 6], &[8, 9]]);
+        assert_eq!(v.col(1).copied().collect::<Vec<_>>(), vec![6, 9]);
+        assert_eq!(v.to_grid(), Grid::new(vec![vec![5, 6], vec![8, 9]]));
+        assert_eq!(v.to_string(), "56\n89\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_outside_test() {
+        let g: Grid<char> = "123\n456\n".parse().unwrap();
+        g.view(2, 0, 2, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ragged_test() {
+        Grid::new(vec![vec![1, 2], vec![3]]);
+    }
+}
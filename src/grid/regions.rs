@@ -0,0 +1,255 @@
+//! Regions of a grid: flood fill, labeling connected regions, and measuring them.
+
+use std::collections::BTreeMap;
+
+use super::{
+    search::{bfs, Neighborhood},
+    Cell, Grid,
+};
+use crate::point::Point;
+
+/// The label of a cell that isn't in a region yet, while labeling.
+const UNLABELED: usize = usize::MAX;
+
+/// The size and shape of one region of a label map.  Produced by Grid::regions.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Region {
+    pub label: usize,
+    /// The number of cells.
+    pub area: usize,
+    /// The number of cell edges facing another region or the outside of the grid.
+    pub perimeter: usize,
+    /// The number of straight sides, counting those around any holes.  This is also its number
+    /// of corners.
+    pub sides: usize,
+    /// The top left of the region's bounding box.
+    pub min: Point<2>,
+    /// The bottom right of the region's bounding box (inclusive).
+    pub max: Point<2>,
+}
+
+impl<T: Copy> Grid<T> {
+    /// Every position reachable from `start` through cells `include` accepts, in reading order.
+    /// Empty if `start` isn't in the grid or isn't accepted itself.
+    pub fn flood_fill(
+        &self,
+        start: Point<2>,
+        neighborhood: Neighborhood,
+        include: impl Fn(Cell<T>) -> bool,
+    ) -> Vec<Point<2>> {
+        if !self
+            .getp(start)
+            .is_some_and(|data| include(Cell::new(start, data)))
+        {
+            return vec![];
+        }
+
+        let search = bfs(
+            start,
+            |pos| {
+                self.neighbors(pos, neighborhood)
+                    .into_iter()
+                    .filter(|cell| include(*cell))
+                    .map(|cell| cell.pos)
+            },
+            |_| false,
+        );
+        let mut filled: Vec<Point<2>> = search.costs.into_keys().collect();
+        filled.sort_by_key(|p| (p.y(), p.x()));
+        filled
+    }
+
+    /// Split the grid into connected regions of cells that are the `same` as each other (which
+    /// should work like `==`), and label each cell with its region's number.  Regions are
+    /// numbered from 0 in reading order of their first cell.
+    pub fn label_regions(
+        &self,
+        neighborhood: Neighborhood,
+        same: impl Fn(T, T) -> bool,
+    ) -> Grid<usize> {
+        let mut labels =
+            Grid::from_cells(self.width, self.height, vec![UNLABELED; self.cells.len()]);
+        let mut next = 0;
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if labels.get(x, y) != Some(UNLABELED) {
+                    continue;
+                }
+                let data = self.cells[y * self.width + x];
+                for p in self.flood_fill([x, y].into(), neighborhood, |cell| same(data, cell.data))
+                {
+                    labels.set(p.x() as usize, p.y() as usize, next);
+                }
+                next += 1;
+            }
+        }
+
+        labels
+    }
+}
+
+impl Grid<usize> {
+    /// Measure each region of a label map, in label order.  Only labels that are on the map
+    /// get a region, so for a map from label_regions (which leaves no gaps) `regions[label]` is
+    /// that label's region, but for other maps the regions should be found by their `label`.
+    pub fn regions(&self) -> Vec<Region> {
+        let mut regions: BTreeMap<usize, Region> = BTreeMap::new();
+
+        for (i, &label) in self.cells.iter().enumerate() {
+            let pos: Point<2> = [i % self.width, i / self.width].into();
+
+            let inside = |cell: Option<Cell<usize>>| cell.is_some_and(|cell| cell.data == label);
+            let adj_4 = self.adj_4(pos);
+            let adj_8 = self.adj_8(pos.x() as usize, pos.y() as usize);
+            // a corner is where both sides are outside the region, or both are inside but the
+            // diagonal between them isn't
+            let corners = [
+                (adj_8.up(), adj_8.left(), adj_8.up_left()),
+                (adj_8.up(), adj_8.right(), adj_8.up_right()),
+                (adj_8.down(), adj_8.left(), adj_8.down_left()),
+                (adj_8.down(), adj_8.right(), adj_8.down_right()),
+            ]
+            .into_iter()
+            .filter(|&(a, b, diagonal)| match (inside(a), inside(b)) {
+                (false, false) => true,
+                (true, true) => !inside(diagonal),
+                _ => false,
+            })
+            .count();
+
+            let region = regions.entry(label).or_insert(Region {
+                label,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                min: pos,
+                max: pos,
+            });
+            region.area += 1;
+            region.perimeter += adj_4.cells.into_iter().filter(|&c| !inside(c)).count();
+            region.sides += corners;
+            region.min = [region.min.x().min(pos.x()), region.min.y().min(pos.y())].into();
+            region.max = [region.max.x().max(pos.x()), region.max.y().max(pos.y())].into();
+        }
+
+        regions.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod regions_tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn flood_fill_test() {
+        let g = grid("..#\n.##\n#..\n");
+        let open = |cell: Cell<char>| cell.data == '.';
+        assert_eq!(
+            g.flood_fill([0, 0].into(), Neighborhood::Four, open),
+            vec![[0, 0].into(), [1, 0].into(), [0, 1].into()]
+        );
+        assert_eq!(
+            g.flood_fill([0, 0].into(), Neighborhood::Eight, open).len(),
+            5
+        );
+        assert_eq!(
+            g.flood_fill([2, 0].into(), Neighborhood::Four, open),
+            vec![]
+        );
+        assert_eq!(
+            g.flood_fill([5, 5].into(), Neighborhood::Four, open),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn label_regions_test() {
+        let g = grid("AAAA\nBBCD\nBBCC\nEEEC\n");
+        let labels = g.label_regions(Neighborhood::Four, |a, b| a == b);
+        #[rustfmt::skip]
+        assert_eq!(
+            labels,
+            Grid::new(vec![
+                vec![0, 0, 0, 0],
+                vec![1, 1, 2, 3],
+                vec![1, 1, 2, 2],
+                vec![4, 4, 4, 2],
+            ])
+        );
+
+        let regions = labels.regions();
+        let stats: Vec<(usize, usize, usize)> = regions
+            .iter()
+            .map(|r| (r.area, r.perimeter, r.sides))
+            .collect();
+        assert_eq!(
+            stats,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(
+            (regions[0].min, regions[0].max),
+            ([0, 0].into(), [3, 0].into())
+        );
+        assert_eq!(
+            (regions[2].min, regions[2].max),
+            ([2, 1].into(), [3, 3].into())
+        );
+    }
+
+    #[test]
+    fn unordered_labels_test() {
+        // labels out of reading order, with gaps, and one far too big to index by
+        #[rustfmt::skip]
+        let labels = Grid::new(vec![
+            vec![7, 7, usize::MAX],
+            vec![2, 7, 7],
+        ]);
+        let regions = labels.regions();
+        let found: Vec<(usize, usize, Point<2>, Point<2>)> = regions
+            .iter()
+            .map(|r| (r.label, r.area, r.min, r.max))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (2, 1, [0, 1].into(), [0, 1].into()),
+                (7, 4, [0, 0].into(), [2, 1].into()),
+                (usize::MAX, 1, [2, 0].into(), [2, 0].into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn holes_test() {
+        let g = grid("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n");
+        let regions = g.label_regions(Neighborhood::Four, |a, b| a == b).regions();
+        assert_eq!(regions.len(), 5);
+        // the outside and the 4 holes
+        assert_eq!(
+            (regions[0].area, regions[0].perimeter, regions[0].sides),
+            (21, 36, 20)
+        );
+        assert!(regions[1..].iter().all(|r| (r.area, r.sides) == (1, 4)));
+
+        let g = grid("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n");
+        let regions = g.label_regions(Neighborhood::Four, |a, b| a == b).regions();
+        let price: usize = regions.iter().map(|r| r.area * r.sides).sum();
+        assert_eq!(price, 368);
+    }
+
+    #[test]
+    fn diagonal_test() {
+        let g = grid("#.\n.#\n");
+        let labels = g.label_regions(Neighborhood::Eight, |a, b| a == b);
+        assert_eq!(labels, Grid::new(vec![vec![0, 1], vec![1, 0]]));
+        let regions = labels.regions();
+        assert_eq!((regions[0].area, regions[0].perimeter), (2, 8));
+        // the cells only touch at a point, so each still has its own 4 corners
+        assert_eq!(regions[0].sides, 8);
+    }
+}
@@ -0,0 +1,351 @@
+//! Shortest paths: breadth-first search, Dijkstra, and A*.
+//!
+//! The searches are generic over their state, so a state can be more than a position, like a
+//! position and the direction it was entered from.  Each takes a `successors` closure giving
+//! the states reachable in one step from a state (with the cost of each step, for Dijkstra and
+//! A*), and a `goal` closure saying when to stop.  A goal that's never reached searches
+//! everything reachable, which is how to get a distance map.
+//!
+//! `Grid` has shortcuts for the common case of searching positions on the grid itself, with its
+//! 4 or 8 neighbours as the steps.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use super::{Cell, Grid};
+use crate::point::Point;
+
+/// Which cells count as next to a cell.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Neighborhood {
+    /// Up, down, left, and right, from Grid::adj_4.
+    Four,
+    /// The diagonals too, from Grid::adj_8.
+    Eight,
+}
+
+/// What a search found: the cost of reaching every state it visited, and how it got there.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    /// The cheapest known cost of reaching each visited state.  For BFS, the number of steps.
+    pub costs: HashMap<S, u64>,
+    /// The state each visited state was reached from, to rebuild paths with.
+    pub came_from: HashMap<S, S>,
+    /// The first goal state reached, if any.
+    pub goal: Option<S>,
+}
+
+impl<S: Copy + Eq + Hash> Search<S> {
+    fn new(start: S) -> Self {
+        Self {
+            costs: HashMap::from([(start, 0)]),
+            came_from: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The cost of reaching a state, if it was reached.
+    pub fn cost(&self, state: S) -> Option<u64> {
+        self.costs.get(&state).copied()
+    }
+
+    /// The states along the path from the start to `state`, both included.
+    pub fn path_to(&self, state: S) -> Option<Vec<S>> {
+        self.costs.get(&state)?;
+        let mut path = vec![state];
+        let mut at = state;
+        while let Some(&previous) = self.came_from.get(&at) {
+            path.push(previous);
+            at = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal that was reached, if one was.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal?)
+    }
+
+    /// The cost of reaching the goal, if it was reached.
+    pub fn goal_cost(&self) -> Option<u64> {
+        self.cost(self.goal?)
+    }
+}
+
+/// Breadth-first search, where every step costs 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(S) -> I,
+    mut goal: impl FnMut(S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if goal(state) {
+            search.goal = Some(state);
+            break;
+        }
+        let steps = search.costs[&state] + 1;
+        for next in successors(state) {
+            if let Entry::Vacant(entry) = search.costs.entry(next) {
+                entry.insert(steps);
+                search.came_from.insert(next, state);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm, for steps with different costs.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(S) -> I,
+    goal: impl FnMut(S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, successors, |_| 0, goal)
+}
+
+/// A*, which is Dijkstra guided by a heuristic: an estimate of the cost from a state to the
+/// goal.  The path found is only the cheapest if the heuristic never overestimates.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(S) -> I,
+    mut heuristic: impl FnMut(S) -> u64,
+    mut goal: impl FnMut(S) -> bool,
+) -> Search<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new(start);
+    // states are kept out of the heap, so they don't need to be Ord
+    let mut states = vec![start];
+    let mut open = BinaryHeap::from([Reverse((heuristic(start), 0, 0))]);
+
+    while let Some(Reverse((_, cost, i))) = open.pop() {
+        let state = states[i];
+        if cost > search.costs[&state] {
+            // already reached more cheaply
+            continue;
+        }
+        if goal(state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, step) in successors(state) {
+            let next_cost = cost + step;
+            if search.cost(next).is_some_and(|known| known <= next_cost) {
+                continue;
+            }
+            search.costs.insert(next, next_cost);
+            search.came_from.insert(next, state);
+            states.push(next);
+            open.push(Reverse((
+                next_cost + heuristic(next),
+                next_cost,
+                states.len() - 1,
+            )));
+        }
+    }
+
+    search
+}
+
+impl<T: Copy> Grid<T> {
+    /// The cells next to `pos` that are inside the grid.
+    pub fn neighbors(&self, pos: Point<2>, neighborhood: Neighborhood) -> Vec<Cell<T>> {
+        match neighborhood {
+            Neighborhood::Four => self.adj_4(pos).cells.into_iter().flatten().collect(),
+            Neighborhood::Eight => {
+                if pos.x() < 0 || pos.y() < 0 {
+                    return vec![];
+                }
+                let adj = self.adj_8(pos.x() as usize, pos.y() as usize);
+                adj.cells.into_iter().flatten().collect()
+            }
+        }
+    }
+
+    /// The number of steps to every position reachable from `start`.  `passable` says whether a
+    /// step can be taken from one cell to the next.
+    pub fn bfs(
+        &self,
+        start: Point<2>,
+        neighborhood: Neighborhood,
+        passable: impl Fn(Cell<T>, Cell<T>) -> bool,
+    ) -> Search<Point<2>> {
+        bfs(
+            start,
+            |pos| {
+                self.steps(pos, neighborhood, |from, to| {
+                    passable(from, to).then_some(1)
+                })
+                .into_iter()
+                .map(|(pos, _)| pos)
+            },
+            |_| false,
+        )
+    }
+
+    /// The cheapest path from `start` to `goal`.  `cost` gives the cost of a step from one cell
+    /// to the next, or None if the step can't be taken.
+    pub fn dijkstra(
+        &self,
+        start: Point<2>,
+        goal: Point<2>,
+        neighborhood: Neighborhood,
+        cost: impl Fn(Cell<T>, Cell<T>) -> Option<u64>,
+    ) -> Search<Point<2>> {
+        dijkstra(
+            start,
+            |pos| self.steps(pos, neighborhood, &cost),
+            |pos| pos == goal,
+        )
+    }
+
+    /// Like dijkstra, but guided towards the goal by the number of steps left if nothing were in
+    /// the way, so it's only certain to find the cheapest path when every step costs at least 1.
+    pub fn astar(
+        &self,
+        start: Point<2>,
+        goal: Point<2>,
+        neighborhood: Neighborhood,
+        cost: impl Fn(Cell<T>, Cell<T>) -> Option<u64>,
+    ) -> Search<Point<2>> {
+        astar(
+            start,
+            |pos| self.steps(pos, neighborhood, &cost),
+            |pos| distance(pos, goal, neighborhood),
+            |pos| pos == goal,
+        )
+    }
+
+    /// The positions one step from `pos`, with their costs.
+    fn steps(
+        &self,
+        pos: Point<2>,
+        neighborhood: Neighborhood,
+        cost: impl Fn(Cell<T>, Cell<T>) -> Option<u64>,
+    ) -> Vec<(Point<2>, u64)> {
+        let Some(data) = self.getp(pos) else {
+            return vec![];
+        };
+        let from = Cell::new(pos, data);
+        self.neighbors(pos, neighborhood)
+            .into_iter()
+            .filter_map(|to| cost(from, to).map(|cost| (to.pos, cost)))
+            .collect()
+    }
+}
+
+/// The fewest steps between two positions on an empty grid.
+fn distance(a: Point<2>, b: Point<2>, neighborhood: Neighborhood) -> u64 {
+    let (dx, dy) = (a.x().abs_diff(b.x()), a.y().abs_diff(b.y()));
+    match neighborhood {
+        Neighborhood::Four => dx + dy,
+        Neighborhood::Eight => dx.max(dy),
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+    use crate::direction::CardDir;
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E
+";
+
+    fn maze() -> (Grid<bool>, Point<2>, Point<2>) {
+        let (grid, markers) = Grid::from_str_with_markers(MAZE, "SE", |c| c != '#').unwrap();
+        (grid, markers[0].pos, markers[1].pos)
+    }
+
+    #[test]
+    fn bfs_test() {
+        let (grid, start, end) = maze();
+        let search = grid.bfs(start, Neighborhood::Four, |_, to| to.data);
+        assert_eq!(search.cost(start), Some(0));
+        assert_eq!(search.cost([2, 2].into()), Some(4));
+        assert_eq!(search.cost(end), Some(15));
+        // walls are never reached
+        assert_eq!(search.cost([3, 0].into()), None);
+
+        let path = search.path_to(end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!((path[0], path[15]), (start, end));
+        assert!(path.iter().all(|&p| grid.getp(p) == Some(true)));
+
+        // diagonal steps can slip between walls
+        let search = grid.bfs(start, Neighborhood::Eight, |_, to| to.data);
+        assert_eq!(search.cost(end), Some(8));
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        // each cell costs its digit to enter
+        let grid =
+            Grid::from_str_with("1163\n1381\n2136\n", |c| c.to_digit(10).unwrap() as u64).unwrap();
+        let (start, end) = ([0, 0].into(), [3, 2].into());
+
+        let search = grid.dijkstra(start, end, Neighborhood::Four, |_, to| Some(to.data));
+        assert_eq!(search.goal_cost(), Some(13));
+        let path = search.path().unwrap();
+        let cost: u64 = path[1..].iter().map(|&p| grid.getp(p).unwrap()).sum();
+        assert_eq!(cost, 13);
+
+        let search = grid.astar(start, end, Neighborhood::Four, |_, to| Some(to.data));
+        assert_eq!(search.goal_cost(), Some(13));
+
+        let (maze, start, end) = maze();
+        let search = maze.astar(start, end, Neighborhood::Four, |_, to| to.data.then_some(1));
+        assert_eq!(search.goal_cost(), Some(15));
+        let search = maze.dijkstra(start, [3, 0].into(), Neighborhood::Four, |_, to| {
+            to.data.then_some(1)
+        });
+        assert_eq!(search.goal, None);
+        assert_eq!(search.path(), None);
+    }
+
+    #[test]
+    fn state_test() {
+        // moving forward costs 1 and turning costs 1000, starting out facing right
+        let (grid, start, end) = maze();
+        let search = dijkstra(
+            (start, CardDir::Right),
+            |(pos, dir)| {
+                let mut next = vec![((pos, dir.cw()), 1000), ((pos, dir.ccw()), 1000)];
+                if let Some(cell) = grid.adj_4(pos).toward(dir).filter(|c| c.data) {
+                    next.push(((cell.pos, dir), 1));
+                }
+                next
+            },
+            |(pos, _)| pos == end,
+        );
+
+        // the only way through, over the top, takes 15 steps and 5 turns
+        assert_eq!(search.goal_cost(), Some(5015));
+        let path = search.path().unwrap();
+        assert_eq!(path.first(), Some(&(start, CardDir::Right)));
+        assert_eq!(path.last().map(|(pos, _)| *pos), Some(end));
+    }
+}
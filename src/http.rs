@@ -0,0 +1,254 @@
+//! Just enough HTTP to talk to the Advent of Code site.
+//!
+//! Plain `http://` URLs are handled here over a `TcpStream`, which is all a local stand-in
+//! server needs.  TLS is out of reach without a dependency, so `https://` URLs go through the
+//! `curl` command instead.
+
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    process::Command,
+    time::Duration,
+};
+
+/// How long to wait on a connection before giving up.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The parts of a response the runner cares about.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// A way of making HTTP requests.
+pub trait Http {
+    fn request(
+        &self,
+        method: &str,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: Option<&str>,
+    ) -> Result<Response, String>;
+}
+
+/// Pick a backend that can handle the URL's scheme.
+pub fn client_for(url: &str) -> Box<dyn Http> {
+    if url.starts_with("http://") {
+        Box::new(TcpHttp)
+    } else {
+        Box::new(CurlHttp)
+    }
+}
+
+/// HTTP/1.0 over a plain TCP connection.  Only handles `http://` URLs.
+pub struct TcpHttp;
+
+impl Http for TcpHttp {
+    fn request(
+        &self,
+        method: &str,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: Option<&str>,
+    ) -> Result<Response, String> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| format!("can't request {url} without TLS, only http:// is supported"))?;
+        let (host, path) = match rest.find('/') {
+            Some(i) => rest.split_at(i),
+            None => (rest, "/"),
+        };
+        let addr = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{host}:80")
+        };
+
+        let mut request = format!("{method} {path} HTTP/1.0\r\nHost: {host}\r\n");
+        for (name, value) in headers {
+            request.push_str(&format!("{name}: {value}\r\n"));
+        }
+        if let Some(body) = body {
+            request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+        request.push_str("\r\n");
+        request.push_str(body.unwrap_or_default());
+
+        let err = |e: std::io::Error| format!("{url}: {e}");
+        let mut stream = TcpStream::connect(&addr).map_err(err)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(err)?;
+        stream.set_write_timeout(Some(TIMEOUT)).map_err(err)?;
+        stream.write_all(request.as_bytes()).map_err(err)?;
+
+        let mut response = vec![];
+        stream.read_to_end(&mut response).map_err(err)?;
+        parse_response(&String::from_utf8_lossy(&response)).map_err(|e| format!("{url}: {e}"))
+    }
+}
+
+/// Shells out to `curl`, which brings its own TLS.
+pub struct CurlHttp;
+
+impl Http for CurlHttp {
+    fn request(
+        &self,
+        method: &str,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: Option<&str>,
+    ) -> Result<Response, String> {
+        let mut curl = Command::new("curl");
+        curl.args(["--silent", "--show-error", "--request", method])
+            .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+            // the status code goes on a line of its own after the body
+            .args(["--write-out", "\n%{http_code}"]);
+        for (name, value) in headers {
+            curl.args(["--header", &format!("{name}: {value}")]);
+        }
+        if let Some(body) = body {
+            curl.args(["--data-raw", body]);
+        }
+        curl.arg(url);
+
+        let output = curl
+            .output()
+            .map_err(|e| format!("can't run curl (is it installed?): {e}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "curl failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or("curl didn't report a status code")?;
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| format!("curl reported a bad status code {status:?}"))?;
+
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Split a raw HTTP response into its status code and body.
+fn parse_response(raw: &str) -> Result<Response, String> {
+    let (head, body) = raw
+        .split_once("\r\n\r\n")
+        .or_else(|| raw.split_once("\n\n"))
+        .unwrap_or((raw, ""));
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or("malformed response status line")?;
+
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// A stand-in server for tests, answering each request with the next canned response.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// Every request the server has received, in full.
+    pub type Requests = Arc<Mutex<Vec<String>>>;
+
+    /// Start a server on a free local port.  Returns its base URL and the requests it receives.
+    pub fn serve(responses: Vec<(u16, &str)>) -> (String, Requests) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Requests::default();
+        let received = requests.clone();
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+                let request = read_request(&mut stream);
+                received.lock().unwrap().push(request);
+                let _ = write!(stream, "HTTP/1.0 {status} Whatever\r\n\r\n{body}");
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    /// Read until the end of the headers, and then the body if there's a Content-Length.
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut request = String::new();
+        let mut buf = [0; 4096];
+        loop {
+            let n = stream.read(&mut buf).unwrap_or(0);
+            request.push_str(&String::from_utf8_lossy(&buf[..n]));
+
+            let complete = request.split_once("\r\n\r\n").is_some_and(|(head, body)| {
+                let length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .and_then(|length| length.trim().parse().ok())
+                    .unwrap_or(0);
+                body.len() >= length
+            });
+            if n == 0 || complete {
+                return request;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod http_tests {
+    use super::*;
+
+    #[test]
+    fn parse_response_test() {
+        let response =
+            parse_response("HTTP/1.1 404 Not Found\r\nContent-Type: text/plain\r\n\r\nnope\n")
+                .unwrap();
+        assert_eq!(response.status, 404);
+        assert_eq!(response.body, "nope\n");
+
+        assert!(parse_response("garbage").is_err());
+    }
+
+    #[test]
+    fn tcp_request_test() {
+        let (base_url, requests) = stand_in::serve(vec![(200, "hello")]);
+        let response = TcpHttp
+            .request(
+                "POST",
+                &format!("{base_url}/a/b"),
+                &[("X-Test", "1")],
+                Some("k=v"),
+            )
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "hello");
+
+        let request = &requests.lock().unwrap()[0];
+        assert!(request.starts_with("POST /a/b HTTP/1.0\r\n"));
+        assert!(request.contains("X-Test: 1\r\n"));
+        assert!(request.ends_with("\r\n\r\nk=v"));
+    }
+}
@@ -0,0 +1,138 @@
+//! Finding and reading puzzle input files.
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+/// The path that means "read from standard input".
+pub const STDIN: &str = "-";
+
+/// The path of a day's puzzle input.
+pub fn input_path(year: u16, day: u8) -> String {
+    format!("input/{year}/d{day}")
+}
+
+/// The directory holding a year's examples.
+pub fn example_dir(year: u16) -> String {
+    format!("examples/{year}")
+}
+
+/// The path of a day's main example input.
+pub fn example_path(year: u16, day: u8) -> String {
+    format!("{}/d{day}", example_dir(year))
+}
+
+/// Read an input file, or standard input if the path is [`STDIN`].
+pub fn read(path: &str) -> io::Result<String> {
+    if path == STDIN {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Expand `*` and `?` wildcards in the file name part of a path, returning the matching files in
+/// sorted order.  Examples' answer sidecars are never matched.  Paths without wildcards
+/// (including [`STDIN`]) are returned unchanged, whether or not they exist.
+pub fn expand(pattern: &str) -> Result<Vec<String>, String> {
+    let path = Path::new(pattern);
+    let Some(name) = path.file_name().map(|n| n.to_string_lossy()) else {
+        return Ok(vec![pattern.to_string()]);
+    };
+    if !name.contains(['*', '?']) {
+        return Ok(vec![pattern.to_string()]);
+    }
+
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let name: Vec<char> = name.chars().collect();
+
+    let entries = fs::read_dir(dir).map_err(|e| format!("can't read {}: {e}", dir.display()))?;
+    let mut matches: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter(|entry| {
+            let sidecar = format!(".{}", crate::answers::SIDECAR_EXTENSION);
+            !entry.file_name().to_string_lossy().ends_with(&sidecar)
+        })
+        .filter(|entry| {
+            let file: Vec<char> = entry.file_name().to_string_lossy().chars().collect();
+            wildcard_match(&name, &file)
+        })
+        .map(|entry| {
+            let file = entry.file_name();
+            match path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                Some(parent) => parent.join(file).to_string_lossy().to_string(),
+                None => file.to_string_lossy().to_string(),
+            }
+        })
+        .collect();
+
+    if matches.is_empty() {
+        return Err(format!("no files match {pattern}"));
+    }
+
+    matches.sort();
+    Ok(matches)
+}
+
+/// Match a name against a pattern where `*` matches any run of characters and `?` matches any
+/// single character.
+fn wildcard_match(pattern: &[char], name: &[char]) -> bool {
+    match (pattern.first(), name.first()) {
+        (None, None) => true,
+        (Some('*'), _) => {
+            wildcard_match(&pattern[1..], name)
+                || (!name.is_empty() && wildcard_match(pattern, &name[1..]))
+        }
+        (Some('?'), Some(_)) => wildcard_match(&pattern[1..], &name[1..]),
+        (Some(p), Some(n)) if p == n => wildcard_match(&pattern[1..], &name[1..]),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod inputs_tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let name: Vec<char> = name.chars().collect();
+        wildcard_match(&pattern, &name)
+    }
+
+    #[test]
+    fn wildcard_match_test() {
+        assert!(matches("d3*", "d3"));
+        assert!(matches("d3*", "d3-p2-test"));
+        assert!(matches("d?", "d3"));
+        assert!(matches("*test", "d3-p2-test"));
+        assert!(matches("d*-*", "d3-test"));
+        assert!(!matches("d3*", "d23"));
+        assert!(!matches("d?", "d12"));
+        assert!(!matches("d3-*", "d3"));
+    }
+
+    #[test]
+    fn expand_test() {
+        let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/2025");
+        let d3 = expand(&format!("{examples}/d3*")).unwrap();
+        assert!(d3.contains(&format!("{examples}/d3")));
+        assert!(d3.contains(&format!("{examples}/d3-test")));
+        assert!(d3.windows(2).all(|w| w[0] < w[1]));
+        assert!(d3.iter().all(|path| !path.ends_with(".answers")));
+
+        assert_eq!(
+            expand("examples/2025/d1").unwrap(),
+            vec!["examples/2025/d1"]
+        );
+        assert_eq!(expand(STDIN).unwrap(), vec![STDIN]);
+        assert!(expand(&format!("{examples}/nothing*")).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! Running code on its own thread, so a panic or a runaway loop can't take the runner down
+//! with it.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Once},
+    thread,
+    time::Duration,
+};
+
+use crate::solution::format_duration;
+
+/// Isolated threads get a bigger stack than the default, since the main thread (where solutions
+/// used to run) has one this big on most platforms.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Why isolated code didn't produce a value.
+#[derive(Debug, Clone)]
+pub enum Failure {
+    /// The code panicked with this message.
+    Panicked(String),
+    /// The code was still running when the timeout ran out.
+    TimedOut(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panicked at {message}"),
+            Failure::TimedOut(timeout) => {
+                write!(f, "timed out after {}", format_duration(*timeout))
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Set on isolated threads, so the panic hook knows to stay quiet.
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+    /// The most recent panic on this thread, with its location.
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run f on a new thread named name.  If it panics, the panic is caught and its message
+/// returned.  If a timeout is given and f is still running when it's up, f is abandoned.
+///
+/// Threads can't be killed, so an abandoned thread keeps running in the background until it
+/// finishes or the process exits.
+pub fn isolate<T, F>(name: String, timeout: Option<Duration>, f: F) -> Result<T, Failure>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    install_hook();
+
+    let (tx, rx) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(name)
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            ISOLATED.set(true);
+            let result = panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+                LAST_PANIC
+                    .take()
+                    .unwrap_or_else(|| panic_message(&*payload))
+            });
+            // the receiver is gone if we've timed out, and then nobody wants the result
+            let _ = tx.send(result);
+        });
+
+    if let Err(e) = spawned {
+        return Err(Failure::Panicked(format!("couldn't spawn a thread: {e}")));
+    }
+
+    let result = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout).map_err(|e| match e {
+            mpsc::RecvTimeoutError::Timeout => Failure::TimedOut(timeout),
+            mpsc::RecvTimeoutError::Disconnected => {
+                Failure::Panicked("thread exited without a result".to_string())
+            }
+        })?,
+        None => rx
+            .recv()
+            .map_err(|_| Failure::Panicked("thread exited without a result".to_string()))?,
+    };
+
+    result.map_err(Failure::Panicked)
+}
+
+/// Replace the panic hook with one that records panics on isolated threads instead of printing
+/// them.  Panics anywhere else are passed to the original hook.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !ISOLATED.get() {
+                default_hook(info);
+                return;
+            }
+
+            let message = panic_message(info.payload());
+            let message = match info.location() {
+                Some(location) => format!("{location}: {message}"),
+                None => message,
+            };
+            LAST_PANIC.set(Some(message));
+        }));
+    });
+}
+
+/// Get the message out of a panic's payload.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
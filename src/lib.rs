@@ -1,18 +1,21 @@
 #![allow(clippy::let_and_return)]
+#![allow(clippy::needless_range_loop)]
 #![allow(unused)]
-pub mod d1;
-pub mod d10;
-pub mod d11;
-pub mod d12;
-pub mod d2;
-pub mod d3;
-pub mod d4;
-pub mod d5;
-pub mod d6;
-pub mod d7;
-pub mod d8;
-pub mod d9;
+pub mod answers;
 pub mod digits;
 pub mod direction;
+pub mod fetch;
 pub mod grid;
+pub mod http;
+pub mod inputs;
+pub mod isolate;
 pub mod point;
+pub mod puzzle;
+pub mod solution;
+pub mod submit;
+pub mod y2025;
+
+use solution::Year;
+
+/// Every year with solutions, oldest first.  Add new years here after adding their module.
+pub const YEARS: &[Year] = &[Year::new(2025, y2025::DAYS)];
@@ -1,205 +1,270 @@
+mod all;
 mod args;
+mod bench;
+mod dashboard;
+mod output;
+mod report;
+mod runner;
+mod scaffold;
+mod watch;
 
-use std::{fmt::Display, fs::read_to_string, process::exit, time::Instant};
-use termion::{color, style};
+use aoc::{
+    answers::{self, Answers},
+    fetch::{self, Fetched, Site},
+    inputs, isolate, puzzle, solution,
+    submit::{self, Guesses, Verdict},
+    YEARS,
+};
+use output::Printer;
+use runner::Runner;
+use std::process::exit;
 
 fn main() {
-    let args = args::parse_args().unwrap_or_else(|_| {
-        eprintln!("Error: parsing CLI arguments failed");
-        print!("{}", args::HELP);
-        std::process::exit(1);
+    let command = args::parse_args().unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        eprint!("\n{}", args::HELP);
+        exit(e.exit_code());
     });
 
-    // day 255 is a magic day number meaning "run all days"
-    if args.day == 255 {
-        for day in 1..=12 {
-            let input = read_to_string(format!("./input/d{}", day));
-            if let Ok(input) = input {
-                run(day, 1, input.clone());
-                run(day, 2, input.clone());
-            } else {
-                print_part(day, 1, Instant::now(), "no input");
-                print_part(day, 2, Instant::now(), "no input");
+    let args = match command {
+        args::Command::Help => {
+            print!("{}", args::HELP);
+            return;
+        }
+        args::Command::Run(args) => args,
+        args::Command::Bench(bench) => {
+            let regressed = bench::bench(&bench);
+            exit(if regressed { 1 } else { 0 });
+        }
+        args::Command::Scaffold(scaffold) => {
+            if let Err(e) = scaffold::scaffold(&scaffold) {
+                eprintln!("Error: {e}");
+                exit(1);
             }
+            return;
         }
-    } else if (1..=12).contains(&args.day) {
-        let input_file = if args.input.is_some() {
-            args.input.unwrap()
-        } else if args.example {
-            format!("./examples/d{}", args.day)
-        } else {
-            format!("./input/d{}", args.day)
-        };
-
-        if let Ok(input) = read_to_string(input_file) {
-            // run requested part, or run both parts if no part is requested
-            if let Some(part) = args.part {
-                run(args.day, part, input);
-            } else {
-                run(args.day, 1, input.clone());
-                run(args.day, 2, input);
+        args::Command::Fetch(args::FetchArgs { year, day }) => {
+            let path = inputs::input_path(year, day);
+            match fetch::fetch_input(&Site::from_env(), year, day, &path) {
+                Ok(Fetched::Downloaded) => println!("input saved: {path}"),
+                Ok(Fetched::Cached) => println!("input for day {day} already exists in {path}"),
+                Err(e) => {
+                    eprintln!("Error: can't fetch {year} day {day}'s input: {e}");
+                    exit(1);
+                }
             }
-        } else {
-            eprintln!(
-                "Error: input file for day {} is missing or unreadable",
-                args.day
-            );
+            return;
+        }
+        args::Command::Examples(args) => {
+            if let Err(e) = examples(args) {
+                eprintln!("Error: {e}");
+                exit(1);
+            }
+            return;
+        }
+        args::Command::Dashboard(args) => {
+            if let Err(e) = dashboard::dashboard(&args) {
+                eprintln!("Error: {e}");
+                exit(1);
+            }
+            return;
         }
+        args::Command::Report(args) => {
+            if let Err(e) = report::report(&args) {
+                eprintln!("Error: {e}");
+                exit(1);
+            }
+            return;
+        }
+        args::Command::Submit(args) => match submit(args) {
+            Ok(Verdict::Correct) => return,
+            Ok(_) => exit(1),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                exit(1);
+            }
+        },
+    };
+
+    let year =
+        solution::find_year(YEARS, args.year).expect("the year is checked when parsing arguments");
+    let answers = Answers::load_year(".", year.year).unwrap_or_else(|e| {
+        eprintln!("Error: couldn't load answers: {e}");
+        exit(1);
+    });
+
+    let mut runner = Runner {
+        printer: Printer::new(args.format),
+        answers,
+        share: args.share,
+        save: args.save,
+        timeout: args.timeout,
+    };
+    runner.printer.header();
+
+    let mut within_budget = true;
+    if args.all {
+        within_budget = all::run_all(&mut runner, year, args.budget);
     } else {
-        eprintln!("Error: DAY must be 1 through 12, or 255 to run all days");
+        let solution = args
+            .day
+            .and_then(|day| year.day(day))
+            .expect("the day is checked when parsing arguments");
+        let input_files = input_files(&args, year.year, solution.day).unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            exit(1);
+        });
+        if args.watch {
+            watch::watch(&mut runner, &args, solution);
+        }
+        runner.run_files(solution, args.part, &input_files);
     }
-}
 
-fn run(day: u8, part: u8, input: String) {
-    if ![1, 2].contains(&part) {
-        eprintln!("Error: part must be 1 or 2");
+    if runner.save {
+        if let Err(e) = runner.answers.save_year(".", year.year) {
+            eprintln!("Error: couldn't save answers: {e}");
+            exit(1);
+        }
+    }
+    if !within_budget {
         exit(1);
     }
+}
 
-    let t = Instant::now();
-    match (day, part) {
-        (1, 1) => {
-            let parsed = aoc2025::d1::parse(input);
-            let output = aoc2025::d1::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (1, 2) => {
-            let parsed = aoc2025::d1::parse(input);
-            let output = aoc2025::d1::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (2, 1) => {
-            let parsed = aoc2025::d2::parse(input);
-            let output = aoc2025::d2::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (2, 2) => {
-            let parsed = aoc2025::d2::parse(input);
-            let output = aoc2025::d2::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (3, 1) => {
-            let parsed = aoc2025::d3::parse(input);
-            let output = aoc2025::d3::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (3, 2) => {
-            let parsed = aoc2025::d3::parse(input);
-            let output = aoc2025::d3::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (4, 1) => {
-            let parsed = aoc2025::d4::parse(input);
-            let output = aoc2025::d4::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (4, 2) => {
-            let parsed = aoc2025::d4::parse(input);
-            let output = aoc2025::d4::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (5, 1) => {
-            let parsed = aoc2025::d5::parse(input);
-            let output = aoc2025::d5::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (5, 2) => {
-            let parsed = aoc2025::d5::parse(input);
-            let output = aoc2025::d5::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (6, 1) => {
-            let parsed = aoc2025::d6::parse(input);
-            let output = aoc2025::d6::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (6, 2) => {
-            let parsed = aoc2025::d6::parse(input);
-            let output = aoc2025::d6::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (7, 1) => {
-            let parsed = aoc2025::d7::parse(input);
-            let output = aoc2025::d7::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (7, 2) => {
-            let parsed = aoc2025::d7::parse(input);
-            let output = aoc2025::d7::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (8, 1) => {
-            let parsed = aoc2025::d8::parse(input);
-            let output = aoc2025::d8::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (8, 2) => {
-            let parsed = aoc2025::d8::parse(input);
-            let output = aoc2025::d8::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (9, 1) => {
-            let parsed = aoc2025::d9::parse(input);
-            let output = aoc2025::d9::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (9, 2) => {
-            let parsed = aoc2025::d9::parse(input);
-            let output = aoc2025::d9::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (10, 1) => {
-            let parsed = aoc2025::d10::parse(input);
-            let output = aoc2025::d10::part1(parsed);
-            print_part(day, part, t, output);
-        }
-        (10, 2) => {
-            let parsed = aoc2025::d10::parse(input);
-            let output = aoc2025::d10::part2(parsed);
-            print_part(day, part, t, output);
-        }
-        (11, 1) => {
-            let parsed = aoc2025::d11::parse(input);
-            let output = aoc2025::d11::part1(parsed);
-            print_part(day, part, t, output);
+/// The input files for a single day's run: the ones given with -i (with wildcards expanded), or
+/// else the day's example or puzzle input.
+fn input_files(args: &args::Args, year: u16, day: u8) -> Result<Vec<String>, String> {
+    if !args.input.is_empty() {
+        let expanded: Result<Vec<Vec<String>>, String> =
+            args.input.iter().map(|i| inputs::expand(i)).collect();
+        Ok(expanded?.concat())
+    } else if args.example {
+        Ok(vec![inputs::example_path(year, day)])
+    } else {
+        Ok(vec![inputs::input_path(year, day)])
+    }
+}
+
+/// Submit an answer, solving the part first if no answer was given.  The verdict is added to the
+/// day's guess history, and a correct answer is saved to the year's answers file.
+fn submit(args: args::SubmitArgs) -> Result<Verdict, String> {
+    let args::SubmitArgs {
+        year,
+        day,
+        part,
+        timeout,
+        answer,
+    } = args;
+    let input_file = inputs::input_path(year, day);
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = solution::find_year(YEARS, year)
+                .and_then(|y| y.day(day))
+                .ok_or_else(|| format!("{year} day {day} has no solution, pass the answer"))?;
+            let solution = *solution;
+            let input = inputs::read(&input_file)
+                .map_err(|e| format!("can't read input file {input_file}: {e}"))?;
+            isolate::isolate(format!("d{day}p{part}"), Some(timeout), move || {
+                solution.run(part, input).answer
+            })
+            .map_err(|failure| format!("can't solve d{day}p{part}: {failure}"))?
         }
-        (11, 2) => {
-            let parsed = aoc2025::d11::parse(input);
-            let output = aoc2025::d11::part2(parsed);
-            print_part(day, part, t, output);
+    };
+
+    let guesses_file = submit::guesses_path(year, day);
+    let mut guesses = Guesses::load(&guesses_file)?;
+    guesses
+        .check(part, &answer)
+        .map_err(|e| format!("not submitting d{day}p{part}: {e}"))?;
+
+    let verdict = submit::submit_answer(&Site::from_env(), year, day, part, &answer)?;
+    println!("d{day}p{part} {answer}: {verdict}");
+
+    guesses.record(part, &answer, verdict.clone());
+    guesses
+        .save(&guesses_file)
+        .map_err(|e| format!("couldn't save {guesses_file}: {e}"))?;
+
+    if verdict == Verdict::Correct {
+        let mut answers = Answers::load_year(".", year)?;
+        let name = answers::input_name(day, &input_file);
+        answers.set(day, &name, part, &answer);
+        answers
+            .save_year(".", year)
+            .map_err(|e| format!("couldn't save answers: {e}"))?;
+    }
+
+    Ok(verdict)
+}
+
+/// Save the examples from a puzzle page to examples/, as dN, dN-2, dN-3, and so on, and record
+/// their answers.  Existing files (other than empty ones) and answers are kept unless forced.
+fn examples(args: args::ExamplesArgs) -> Result<(), String> {
+    let args::ExamplesArgs {
+        year,
+        day,
+        page,
+        force,
+    } = args;
+
+    let html = match page {
+        Some(page) => inputs::read(&page).map_err(|e| format!("can't read {page}: {e}"))?,
+        None => {
+            let response = Site::from_env().request("GET", &format!("/{year}/day/{day}"), None)?;
+            if response.status != 200 {
+                return Err(format!(
+                    "can't download {year} day {day}'s puzzle, HTTP status {}",
+                    response.status
+                ));
+            }
+            response.body
         }
-        (12, 1) => {
-            let parsed = aoc2025::d12::parse(input);
-            let output = aoc2025::d12::part1(parsed);
-            print_part(day, part, t, output);
+    };
+
+    let examples = puzzle::extract(&html);
+    if examples.is_empty() {
+        return Err("no examples found on the puzzle page".to_string());
+    }
+
+    let mut answers = Answers::load_year(".", year)?;
+    let mut answers_changed = false;
+
+    for (i, example) in examples.iter().enumerate() {
+        let path = match i {
+            0 => inputs::example_path(year, day),
+            i => format!("{}-{}", inputs::example_path(year, day), i + 1),
+        };
+
+        let exists = std::fs::metadata(&path).is_ok_and(|m| m.len() > 0);
+        if exists && !force {
+            println!("example already exists in {path}");
+        } else {
+            if let Some(dir) = std::path::Path::new(&path).parent() {
+                std::fs::create_dir_all(dir)
+                    .map_err(|e| format!("can't create {}: {e}", dir.display()))?;
+            }
+            std::fs::write(&path, &example.input)
+                .map_err(|e| format!("can't write {path}: {e}"))?;
+            println!("example saved: {path}");
         }
-        (12, 2) => {
-            let parsed = aoc2025::d12::parse(input);
-            let output = aoc2025::d12::part2(parsed);
-            print_part(day, part, t, output);
+
+        let name = answers::input_name(day, &path);
+        for (part, answer) in &example.answers {
+            if force || answers.get(day, &name, *part).is_none() {
+                answers.set(day, &name, *part, answer);
+                answers_changed = true;
+                println!("d{day}p{part} {name} answer saved: {answer}");
+            }
         }
-        _ => unimplemented!(),
     }
-}
 
-fn print_part<T: Display>(day: u8, part: u8, time: Instant, answer: T) {
-    let t = if time.elapsed().as_nanos() > 1_000_000_000 {
-        ((time.elapsed().as_millis() as f32) / 1000.0, "s")
-    } else if time.elapsed().as_nanos() > 1_000_000 {
-        ((time.elapsed().as_micros() as f32) / 1000.0, "ms")
-    } else if time.elapsed().as_nanos() > 1_000 {
-        ((time.elapsed().as_nanos() as f32) / 1000.0, "μs")
-    } else {
-        (time.elapsed().as_nanos() as f32, "ns")
-    };
+    if answers_changed {
+        answers
+            .save_year(".", year)
+            .map_err(|e| format!("couldn't save answers: {e}"))?;
+    }
 
-    println!(
-        "{green}🎄{reset} {blue}d{day}p{part}{reset} {answer} {grey}({time}{time_suf})",
-        blue = color::Fg(color::Blue),
-        green = color::Fg(color::Green),
-        grey = color::Fg(color::LightBlack),
-        reset = style::Reset,
-        time = t.0,
-        time_suf = t.1,
-    );
+    Ok(())
 }
@@ -0,0 +1,365 @@
+use std::{collections::BTreeMap, str::FromStr, time::Duration};
+
+pub use aoc::solution::format_duration;
+use aoc::{answers::Status, isolate::Failure, solution::PartRun};
+use termion::{color, style};
+
+/// How the runner prints results.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum Format {
+    /// Colored, human-readable lines.
+    #[default]
+    Pretty,
+    /// Human-readable lines without any ANSI escapes.
+    Plain,
+    /// One JSON object per line.
+    Json,
+    /// Comma-separated values with a header row.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(Format::Pretty),
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format {s:?}, expected pretty, plain, json, or csv"
+            )),
+        }
+    }
+}
+
+/// Why a part has no answer, as recorded in JSON output so other tools don't have to match on
+/// the message.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// The input file is missing or can't be read.
+    NoInput,
+    /// The part panicked.
+    Panicked,
+    /// The part was still running when the timeout ran out.
+    TimedOut,
+}
+
+impl ErrorKind {
+    /// The kind's name in JSON output.
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorKind::NoInput => "no_input",
+            ErrorKind::Panicked => "panicked",
+            ErrorKind::TimedOut => "timed_out",
+        }
+    }
+}
+
+impl FromStr for ErrorKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "no_input" => Ok(ErrorKind::NoInput),
+            "panicked" => Ok(ErrorKind::Panicked),
+            "timed_out" => Ok(ErrorKind::TimedOut),
+            _ => Err(format!("unknown error kind {s:?}")),
+        }
+    }
+}
+
+impl From<&Failure> for ErrorKind {
+    fn from(failure: &Failure) -> Self {
+        match failure {
+            Failure::Panicked(_) => ErrorKind::Panicked,
+            Failure::TimedOut(_) => ErrorKind::TimedOut,
+        }
+    }
+}
+
+/// One part's result, read back from a line of JSON output.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Record {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Option<String>,
+    pub solve: Option<Duration>,
+    /// Why there's no answer, if there isn't one.
+    pub error: Option<ErrorKind>,
+    pub message: Option<String>,
+}
+
+/// Prints results in the chosen format.
+pub struct Printer {
+    pub format: Format,
+    /// Label pretty output with the input file, for runs with more than one input.
+    pub show_input: bool,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            show_input: false,
+        }
+    }
+
+    /// Print anything that must come before the first record (the CSV header row).
+    pub fn header(&self) {
+        if self.format == Format::Csv {
+            println!("day,part,answer,status,parse_ns,solve_ns,input,error");
+        }
+    }
+
+    /// Print the result of running a part against the given input file, and whether the answer
+    /// is known to be correct.
+    pub fn part(&self, run: &PartRun, input: &str, status: &Status) {
+        let parse = run
+            .parse
+            .map(format_duration)
+            .unwrap_or_else(|| "shared".to_string());
+        let solve = format_duration(run.solve);
+        let label = self.label(input);
+
+        match self.format {
+            Format::Pretty => println!(
+                "{green}🎄{reset} {blue}d{day}p{part}{reset} {answer} {status}{grey}(parse {parse}, solve {solve}){label}{reset}",
+                blue = color::Fg(color::Blue),
+                green = color::Fg(color::Green),
+                grey = color::Fg(color::LightBlack),
+                reset = style::Reset,
+                day = run.day,
+                part = run.part,
+                answer = run.answer,
+                status = match status {
+                    Status::Correct => "✅ ".to_string(),
+                    Status::Wrong(expected) => format!(
+                        "❌ {red}expected {expected}{reset} ",
+                        red = color::Fg(color::Red),
+                        reset = style::Reset,
+                    ),
+                    Status::Unknown => "❔ ".to_string(),
+                },
+            ),
+            Format::Plain => println!(
+                "d{day}p{part} {answer} {status} (parse {parse}, solve {solve}) {input}",
+                day = run.day,
+                part = run.part,
+                answer = run.answer,
+            ),
+            Format::Json => println!(
+                r#"{{"day":{day},"part":{part},"answer":{answer},"status":"{status}","parse_ns":{parse},"solve_ns":{solve},"input":{input},"error":null,"message":null}}"#,
+                day = run.day,
+                part = run.part,
+                answer = json_string(&run.answer),
+                parse = run
+                    .parse
+                    .map(|p| p.as_nanos().to_string())
+                    .unwrap_or_else(|| "null".to_string()),
+                solve = run.solve.as_nanos(),
+                input = json_string(input),
+            ),
+            Format::Csv => println!(
+                "{day},{part},{answer},{status},{parse},{solve},{input},",
+                day = run.day,
+                part = run.part,
+                answer = csv_field(&run.answer),
+                parse = run
+                    .parse
+                    .map(|p| p.as_nanos().to_string())
+                    .unwrap_or_default(),
+                solve = run.solve.as_nanos(),
+                input = csv_field(input),
+            ),
+        }
+    }
+
+    /// Print a part that produced no answer, with a message saying why.
+    pub fn error(&self, day: u8, part: u8, input: &str, kind: ErrorKind, message: &str) {
+        match self.format {
+            Format::Pretty => println!(
+                "{green}🎄{reset} {blue}d{day}p{part}{reset} {message}{grey}{label}{reset}",
+                blue = color::Fg(color::Blue),
+                green = color::Fg(color::Green),
+                grey = color::Fg(color::LightBlack),
+                reset = style::Reset,
+                label = self.label(input),
+            ),
+            Format::Plain => println!("d{day}p{part} {message} {input}"),
+            Format::Json => println!(
+                r#"{{"day":{day},"part":{part},"answer":null,"status":null,"parse_ns":null,"solve_ns":null,"input":{input},"error":"{kind}","message":{message}}}"#,
+                input = json_string(input),
+                kind = kind.name(),
+                message = json_string(message),
+            ),
+            Format::Csv => println!(
+                "{day},{part},,,,,{input},{message}",
+                input = csv_field(input),
+                message = csv_field(message),
+            ),
+        }
+    }
+}
+
+impl Printer {
+    /// The input file label for pretty output, if it should have one.
+    fn label(&self, input: &str) -> String {
+        if self.show_input {
+            format!(" {input}")
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Quote and escape a string for use as a JSON value.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quote a CSV field if it contains anything that would break the row apart.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Parse a record from a line of JSON output.  Anything else (like a solution's own output)
+/// isn't a record.
+pub fn parse_record(line: &str) -> Option<Record> {
+    if !line.starts_with("{\"day\":") {
+        return None;
+    }
+    let fields = json_fields(line)?;
+    let field = |key: &str| fields.get(key).cloned().flatten();
+
+    Some(Record {
+        part: field("part")?.parse().ok()?,
+        answer: field("answer"),
+        status: field("status"),
+        solve: field("solve_ns")
+            .and_then(|ns| ns.parse().ok())
+            .map(Duration::from_nanos),
+        error: field("error").and_then(|kind| kind.parse().ok()),
+        message: field("message"),
+    })
+}
+
+/// The fields of a flat JSON object whose values are strings, numbers, or null, like the
+/// records above.  Null values are `None`.
+fn json_fields(json: &str) -> Option<BTreeMap<String, Option<String>>> {
+    let mut fields = BTreeMap::new();
+    let mut chars = json
+        .trim()
+        .strip_prefix('{')?
+        .strip_suffix('}')?
+        .chars()
+        .peekable();
+
+    loop {
+        match chars.next() {
+            None => return Some(fields),
+            Some(',') | Some(' ') => continue,
+            Some('"') => {}
+            Some(_) => return None,
+        }
+        let key = read_json_string(&mut chars)?;
+        if chars.next() != Some(':') {
+            return None;
+        }
+
+        let value = if chars.peek() == Some(&'"') {
+            chars.next();
+            Some(read_json_string(&mut chars)?)
+        } else {
+            let mut value = String::new();
+            while let Some(&c) = chars.peek() {
+                if c == ',' {
+                    break;
+                }
+                value.push(c);
+                chars.next();
+            }
+            let value = value.trim().to_string();
+            (value != "null").then_some(value)
+        };
+        fields.insert(key, value);
+    }
+}
+
+/// Read the rest of a JSON string whose opening quote has already been read.
+fn read_json_string(chars: &mut impl Iterator<Item = char>) -> Option<String> {
+    let mut s = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(s),
+            '\\' => match chars.next()? {
+                'n' => s.push('\n'),
+                'r' => s.push('\r'),
+                't' => s.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    s.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => s.push(c),
+            },
+            c => s.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod output_tests {
+    use super::*;
+
+    #[test]
+    fn parse_record_test() {
+        let line = r#"{"day":3,"part":2,"answer":"a\"b\nc","status":"wrong","parse_ns":10,"solve_ns":1500,"input":"input/2025/d3","error":null,"message":null}"#;
+        assert_eq!(
+            parse_record(line),
+            Some(Record {
+                part: 2,
+                answer: Some("a\"b\nc".to_string()),
+                status: Some("wrong".to_string()),
+                solve: Some(Duration::from_nanos(1500)),
+                error: None,
+                message: None,
+            })
+        );
+
+        let failed = r#"{"day":3,"part":1,"answer":null,"status":null,"parse_ns":null,"solve_ns":null,"input":"x, y","error":"panicked","message":"panicked at src/y2025/d3.rs:10:5: oops"}"#;
+        let record = parse_record(failed).unwrap();
+        assert_eq!(record.answer, None);
+        assert_eq!(record.error, Some(ErrorKind::Panicked));
+        assert_eq!(
+            record.message.as_deref(),
+            Some("panicked at src/y2025/d3.rs:10:5: oops")
+        );
+
+        let missing = r#"{"day":3,"part":1,"answer":null,"status":null,"parse_ns":null,"solve_ns":null,"input":"input/2025/d3","error":"no_input","message":"can't read input file input/2025/d3"}"#;
+        assert_eq!(
+            parse_record(missing).unwrap().error,
+            Some(ErrorKind::NoInput)
+        );
+
+        assert_eq!(parse_record("debugging output"), None);
+    }
+}
@@ -0,0 +1,224 @@
+//! Pulling example inputs and their answers out of a puzzle's web page.
+//!
+//! A puzzle page has one `<article>` per unlocked part.  Example inputs are in `<pre><code>`
+//! blocks, but so are the diagrams that walk through them, so a block only counts as an example
+//! if it's the first in its article or the paragraph leading up to it says it is one ("For
+//! example:").  The answer each part gets for its example is the last `<code><em>` in the
+//! part's article.  That answer belongs to the latest example before it, or the first example
+//! on the page if the part doesn't have one of its own (part 2 usually reuses part 1's example).
+
+/// One example input from a puzzle page.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Example {
+    pub input: String,
+    /// The answers the example is expected to give, as (part, answer).
+    pub answers: Vec<(u8, String)>,
+}
+
+/// Find every example on a puzzle page, in page order, along with their answers.
+pub fn extract(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    let mut articles = articles(html);
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    for (i, article) in articles.iter().enumerate() {
+        let part = i as u8 + 1;
+        let mut latest = None;
+        let mut answer = None;
+        let mut first_block = true;
+        let mut rest = *article;
+
+        loop {
+            let block = rest.find("<pre><code>");
+            let emphasis = rest.find("<code><em>");
+            match (block, emphasis) {
+                (Some(b), e) if e.is_none_or(|e| b < e) => {
+                    let Some((inner, after)) = between(&rest[b..], "<pre><code>", "</code></pre>")
+                    else {
+                        break;
+                    };
+                    if first_block || introduces_example(&rest[..b]) {
+                        examples.push(Example {
+                            input: text(inner),
+                            answers: vec![],
+                        });
+                        latest = Some(examples.len() - 1);
+                    }
+                    first_block = false;
+                    rest = after;
+                }
+                (_, Some(e)) => {
+                    let Some((inner, after)) = between(&rest[e..], "<code><em>", "</em></code>")
+                    else {
+                        break;
+                    };
+                    answer = Some((latest, text(inner)));
+                    rest = after;
+                }
+                _ => break,
+            }
+        }
+
+        if let Some((example, answer)) = answer {
+            if let Some(example) = example.or((!examples.is_empty()).then_some(0)) {
+                examples[example].answers.push((part, answer));
+            }
+        }
+    }
+
+    examples
+}
+
+/// Whether the paragraph just before a block (the last one in `lead_in`) says the block is an
+/// example, like "For example:" or "Here's a larger example:".
+fn introduces_example(lead_in: &str) -> bool {
+    let paragraph = lead_in.rfind("<p>").map_or(lead_in, |i| &lead_in[i..]);
+    let paragraph = text(paragraph).to_lowercase();
+    paragraph.contains("example") || paragraph.contains("for instance")
+}
+
+/// The contents of each `<article>` on the page.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some((_, inner)) = rest[start..].split_once('>') else {
+            break;
+        };
+        let Some((article, after)) = inner.split_once("</article>") else {
+            break;
+        };
+        articles.push(article);
+        rest = after;
+    }
+    articles
+}
+
+/// Split s after the opening tag and around the closing tag, returning what's between them and
+/// what comes after.
+fn between<'a>(s: &'a str, open: &str, close: &str) -> Option<(&'a str, &'a str)> {
+    s.strip_prefix(open)?.split_once(close)
+}
+
+/// Strip the tags out of some HTML and decode its entities.
+fn text(html: &str) -> String {
+    let mut stripped = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    stripped
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod puzzle_tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Example ---</h2>
+<p>For example:</p>
+<pre><code>L68
+R48
+</code></pre>
+<p>It ends at <code>50</code> and then <code>0</code>.</p>
+<p>Here, the password is <code><em>3</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1191</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Arrows like <code>a-&gt;b</code>, and a new example:</p>
+<pre><code>a-&gt;<em>b</em>
+&amp;c
+</code></pre>
+<p>Now the password is <code><em>6</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn extract_test() {
+        let examples = extract(PAGE);
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    input: "L68\nR48\n".to_string(),
+                    answers: vec![(1, "3".to_string())],
+                },
+                Example {
+                    input: "a->b\n&c\n".to_string(),
+                    answers: vec![(2, "6".to_string())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn shared_example_test() {
+        // part 2 without an example of its own uses the first one
+        let page = PAGE.replace("<pre><code>a-&gt;<em>b</em>\n&amp;c\n</code></pre>", "");
+        let examples = extract(&page);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            examples[0].answers,
+            vec![(1, "3".to_string()), (2, "6".to_string())]
+        );
+
+        assert!(extract("<html>nothing here</html>").is_empty());
+    }
+
+    #[test]
+    fn diagrams_test() {
+        // the blocks after the example show it being worked through, and aren't examples
+        let page = r#"<article class="day-desc"><h2>--- Day 4: Example ---</h2>
+<p>Consider this grid:</p>
+<pre><code>..@
+@@.
+</code></pre>
+<p>After the first round:</p>
+<pre><code>..x
+x@.
+</code></pre>
+<p>After the second round:</p>
+<pre><code>...
+.x.
+</code></pre>
+<p>In all, <code><em>3</em></code> rolls are removed.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Here's a larger example:</p>
+<pre><code>@@@
+@.@
+</code></pre>
+<p>Which ends with:</p>
+<pre><code>...
+...
+</code></pre>
+<p>This time <code><em>5</em></code> are removed.</p>
+</article>"#;
+        assert_eq!(
+            extract(page),
+            vec![
+                Example {
+                    input: "..@\n@@.\n".to_string(),
+                    answers: vec![(1, "3".to_string())],
+                },
+                Example {
+                    input: "@@@\n@.@\n".to_string(),
+                    answers: vec![(2, "5".to_string())],
+                },
+            ]
+        );
+    }
+}
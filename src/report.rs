@@ -0,0 +1,317 @@
+//! A table of every day's results, as Markdown for the README or as a standalone web page.
+
+use std::{fs, time::Duration};
+
+use aoc::{
+    answers::{Answers, Status},
+    inputs,
+    solution::{self, STUB_ANSWER},
+    YEARS,
+};
+
+use crate::{args::ReportArgs, bench, output::format_duration, runner};
+
+/// The README, where `--readme` puts the table.
+const README_FILE: &str = "README.md";
+
+/// The table goes between these lines in the README, replacing whatever was there.
+const START_MARKER: &str = "<!-- report start -->";
+const END_MARKER: &str = "<!-- report end -->";
+
+/// How one part fared on its puzzle input.
+#[derive(Debug, Clone, PartialEq)]
+enum Outcome {
+    /// The answer was right, and took this long (the median).
+    Correct(Duration),
+    Wrong(Duration),
+    /// There's no known answer to check against.
+    Unknown(Duration),
+    /// Still answering with the template's placeholder.
+    Stub,
+    /// It panicked or timed out.
+    Failed(String),
+    NoInput,
+}
+
+impl Outcome {
+    fn label(&self) -> &'static str {
+        match self {
+            Outcome::Correct(_) => "✅",
+            Outcome::Wrong(_) => "❌ wrong",
+            Outcome::Unknown(_) => "❔ unchecked",
+            Outcome::Stub => "not solved",
+            Outcome::Failed(_) => "💥 failed",
+            Outcome::NoInput => "no input",
+        }
+    }
+
+    fn median(&self) -> Option<Duration> {
+        match self {
+            Outcome::Correct(t) | Outcome::Wrong(t) | Outcome::Unknown(t) => Some(*t),
+            _ => None,
+        }
+    }
+}
+
+/// One day's row in the table.
+#[derive(Debug, Clone, PartialEq)]
+struct DayReport {
+    day: u8,
+    /// The puzzle's page, from the `//!` header of the day's module.
+    url: Option<String>,
+    parts: [Outcome; 2],
+}
+
+/// Run the report subcommand.
+pub fn report(args: &ReportArgs) -> Result<(), String> {
+    let year =
+        solution::find_year(YEARS, args.year).expect("the year is checked when parsing arguments");
+    let answers = Answers::load_year(".", year.year)?;
+
+    let mut days = vec![];
+    for solution in year.days {
+        let day = solution.day;
+        let url = fs::read_to_string(format!("src/y{}/d{day}.rs", year.year))
+            .ok()
+            .and_then(|source| doc_url(&source));
+
+        let Ok(input) = inputs::read(&inputs::input_path(year.year, day)) else {
+            eprintln!("d{day}: no input");
+            days.push(DayReport {
+                day,
+                url,
+                parts: [Outcome::NoInput, Outcome::NoInput],
+            });
+            continue;
+        };
+
+        let parts = [1, 2].map(|part| {
+            eprintln!("d{day}p{part}: running");
+            let (_, result) = runner::solve(
+                solution,
+                Some(part),
+                input.clone(),
+                false,
+                Some(args.timeout),
+            )
+            .remove(0);
+
+            match result {
+                Err(failure) => Outcome::Failed(failure.to_string()),
+                Ok(run) if run.answer == STUB_ANSWER => Outcome::Stub,
+                Ok(run) => {
+                    let median = match bench::measure(
+                        solution,
+                        part,
+                        &input,
+                        args.runs,
+                        args.warmup,
+                        Some(args.timeout),
+                    ) {
+                        Ok(stats) => stats.median,
+                        Err(failure) => return Outcome::Failed(failure.to_string()),
+                    };
+                    match answers.check(day, "input", part, &run.answer) {
+                        Status::Correct => Outcome::Correct(median),
+                        Status::Wrong(_) => Outcome::Wrong(median),
+                        Status::Unknown => Outcome::Unknown(median),
+                    }
+                }
+            }
+        });
+        days.push(DayReport { day, url, parts });
+    }
+
+    let table = markdown(&days, args.runs);
+    match &args.output {
+        Some(path) => write(path, &table)?,
+        None if !args.readme => print!("{table}"),
+        None => {}
+    }
+    if let Some(path) = &args.html {
+        write(path, &html(year.year, &days, args.runs))?;
+    }
+    if args.readme {
+        let readme = fs::read_to_string(README_FILE)
+            .map_err(|e| format!("can't read {README_FILE}: {e}"))?;
+        write(README_FILE, &replace_report(&readme, &table)?)?;
+    }
+
+    Ok(())
+}
+
+/// The first link in a module's `//!` header.
+fn doc_url(source: &str) -> Option<String> {
+    source
+        .lines()
+        .take_while(|line| line.starts_with("//!"))
+        .flat_map(|line| line.split_whitespace())
+        .find(|word| word.starts_with("https://") || word.starts_with("http://"))
+        .map(String::from)
+}
+
+fn markdown(days: &[DayReport], runs: usize) -> String {
+    let mut table =
+        "| Day | Part 1 | Time | Part 2 | Time |\n| --- | --- | ---: | --- | ---: |\n".to_string();
+    for day in days {
+        let link = match &day.url {
+            Some(url) => format!("[{}]({url})", day.day),
+            None => day.day.to_string(),
+        };
+        let [p1, p2] = &day.parts;
+        table.push_str(&format!(
+            "| {link} | {} | {} | {} | {} |\n",
+            p1.label(),
+            time(p1),
+            p2.label(),
+            time(p2)
+        ));
+    }
+    table.push_str(&format!(
+        "\nTimes are the median of {} on the puzzle input, from `aoc report`.\n",
+        plural(runs, "run")
+    ));
+    table
+}
+
+fn html(year: u16, days: &[DayReport], runs: usize) -> String {
+    let runs = plural(runs, "run");
+    let mut rows = String::new();
+    for day in days {
+        let link = match &day.url {
+            Some(url) => format!("<a href=\"{}\">{}</a>", escape(url), day.day),
+            None => day.day.to_string(),
+        };
+        rows.push_str(&format!("      <tr><td>{link}</td>"));
+        for part in &day.parts {
+            let title = match part {
+                Outcome::Failed(why) => format!(" title=\"{}\"", escape(why)),
+                _ => String::new(),
+            };
+            rows.push_str(&format!(
+                "<td{title}>{}</td><td class=\"time\">{}</td>",
+                part.label(),
+                time(part)
+            ));
+        }
+        rows.push_str("</tr>\n");
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>Advent of Code {year}</title>
+  <style>
+    body {{ font-family: sans-serif; margin: 2em; }}
+    table {{ border-collapse: collapse; }}
+    th, td {{ padding: 0.25em 1em; border-bottom: 1px solid #ddd; text-align: left; }}
+    .time {{ text-align: right; font-family: monospace; }}
+  </style>
+</head>
+<body>
+  <h1>Advent of Code {year}</h1>
+  <table>
+    <thead>
+      <tr><th>Day</th><th>Part 1</th><th class="time">Time</th><th>Part 2</th><th class="time">Time</th></tr>
+    </thead>
+    <tbody>
+{rows}    </tbody>
+  </table>
+  <p>Times are the median of {runs} on the puzzle input.</p>
+</body>
+</html>
+"#
+    )
+}
+
+fn time(outcome: &Outcome) -> String {
+    outcome.median().map(format_duration).unwrap_or_default()
+}
+
+fn plural(n: usize, noun: &str) -> String {
+    match n {
+        1 => format!("1 {noun}"),
+        n => format!("{n} {noun}s"),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Put the table between the report markers, replacing the old one.
+fn replace_report(readme: &str, table: &str) -> Result<String, String> {
+    let missing = || format!("{README_FILE} needs a {START_MARKER} line and a {END_MARKER} line");
+    let (before, rest) = readme.split_once(START_MARKER).ok_or_else(missing)?;
+    let (_, after) = rest.split_once(END_MARKER).ok_or_else(missing)?;
+    Ok(format!(
+        "{before}{START_MARKER}\n{table}{END_MARKER}{after}"
+    ))
+}
+
+fn write(path: &str, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("can't write {path}: {e}"))
+}
+
+#[cfg(test)]
+mod report_tests {
+    use super::*;
+
+    fn days() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 1,
+                url: Some("https://adventofcode.com/2025/day/1".to_string()),
+                parts: [
+                    Outcome::Correct(Duration::from_micros(12)),
+                    Outcome::Failed("timed out after 10s".to_string()),
+                ],
+            },
+            DayReport {
+                day: 2,
+                url: None,
+                parts: [Outcome::NoInput, Outcome::Stub],
+            },
+        ]
+    }
+
+    #[test]
+    fn markdown_test() {
+        let table = markdown(&days(), 10);
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            rows[2],
+            "| [1](https://adventofcode.com/2025/day/1) | ✅ | 12μs | 💥 failed |  |"
+        );
+        assert_eq!(rows[3], "| 2 | no input |  | not solved |  |");
+        assert!(table.ends_with("median of 10 runs on the puzzle input, from `aoc report`.\n"));
+
+        let page = html(2025, &days(), 10);
+        assert!(page.contains("<td title=\"timed out after 10s\">💥 failed</td>"));
+    }
+
+    #[test]
+    fn doc_url_test() {
+        let source = "//! A solution to day 1 year 2025.\n//! https://adventofcode.com/2025/day/1\n\nuse x; // https://example.com\n";
+        assert_eq!(
+            doc_url(source).as_deref(),
+            Some("https://adventofcode.com/2025/day/1")
+        );
+        assert_eq!(doc_url("use x; // https://example.com\n"), None);
+    }
+
+    #[test]
+    fn replace_report_test() {
+        let readme = format!("# Title\n\n{START_MARKER}\nold\n{END_MARKER}\n\nmore\n");
+        assert_eq!(
+            replace_report(&readme, "new\n").unwrap(),
+            format!("# Title\n\n{START_MARKER}\nnew\n{END_MARKER}\n\nmore\n")
+        );
+        assert!(replace_report("# Title\n", "new\n").is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use aoc::{
+    answers::{self, Answers, Status},
+    inputs,
+    isolate::{isolate, Failure},
+    solution::{Day, PartRun},
+};
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use crate::output::{ErrorKind, Printer};
+
+/// Each answer from a run, or why there isn't one, keyed by input file and part.
+pub type Answered = BTreeMap<(String, u8), Result<String, String>>;
+
+/// Runs solutions, prints their results, and checks them against the known answers.
+pub struct Runner {
+    pub printer: Printer,
+    pub answers: Answers,
+    /// parse the input once and share it between both parts
+    pub share: bool,
+    /// record each answer as the correct one
+    pub save: bool,
+    /// give up on any part that takes longer than this
+    pub timeout: Option<Duration>,
+}
+
+impl Runner {
+    /// Run the requested part, or both parts if no part is requested, and print the results.
+    /// Returns each part's answer, or why it doesn't have one.
+    pub fn run(
+        &mut self,
+        solution: &Day,
+        part: Option<u8>,
+        input_file: &str,
+        input: String,
+    ) -> Vec<(u8, Result<String, String>)> {
+        let mut answers = vec![];
+        for (part, result) in solve(solution, part, input, self.share, self.timeout) {
+            match result {
+                Ok(run) => {
+                    self.report(&run, input_file);
+                    answers.push((part, Ok(run.answer)));
+                }
+                Err(failure) => {
+                    let message = failure.to_string();
+                    let kind = ErrorKind::from(&failure);
+                    self.printer
+                        .error(solution.day, part, input_file, kind, &message);
+                    answers.push((part, Err(message)));
+                }
+            }
+        }
+        answers
+    }
+
+    /// Run the requested parts on each input file in turn.  A file that can't be read is
+    /// reported as a missing input for each requested part, and the rest are still run.
+    pub fn run_files(
+        &mut self,
+        solution: &Day,
+        part: Option<u8>,
+        input_files: &[String],
+    ) -> Answered {
+        self.printer.show_input = input_files.len() > 1;
+
+        let mut answered = Answered::new();
+        for input_file in input_files {
+            let label = if input_file == inputs::STDIN {
+                "stdin"
+            } else {
+                input_file
+            };
+
+            match inputs::read(input_file) {
+                Ok(input) => {
+                    for (part, answer) in self.run(solution, part, label, input) {
+                        answered.insert((label.to_string(), part), answer);
+                    }
+                }
+                Err(e) => {
+                    let message = format!("can't read input file {label}: {e}");
+                    for part in part.map_or(vec![1, 2], |part| vec![part]) {
+                        self.printer.error(
+                            solution.day,
+                            part,
+                            input_file,
+                            ErrorKind::NoInput,
+                            &message,
+                        );
+                    }
+                }
+            }
+        }
+        answered
+    }
+
+    /// Print a part's result along with whether it matches the known answer.
+    pub fn report(&mut self, run: &PartRun, input_file: &str) -> Status {
+        let name = answers::input_name(run.day, input_file);
+        let status = self.answers.check(run.day, &name, run.part, &run.answer);
+        self.printer.part(run, input_file, &status);
+
+        if self.save {
+            self.answers.set(run.day, &name, run.part, &run.answer);
+        }
+
+        status
+    }
+}
+
+/// Solve the requested part, or both parts if no part is requested.  Each part runs isolated on
+/// its own thread, so a part that panics or times out is returned as a failure.
+pub fn solve(
+    solution: &Day,
+    part: Option<u8>,
+    input: String,
+    share: bool,
+    timeout: Option<Duration>,
+) -> Vec<(u8, Result<PartRun, Failure>)> {
+    let solution = *solution;
+    let name = |part| format!("d{}p{part}", solution.day);
+
+    match part {
+        Some(part) => vec![(
+            part,
+            isolate(name(part), timeout, move || solution.run(part, input)),
+        )],
+        None if share && solution.is_shared() => solve_shared(solution, input, timeout),
+        None => [1, 2]
+            .map(|part| {
+                let input = input.clone();
+                (
+                    part,
+                    isolate(name(part), timeout, move || solution.run(part, input)),
+                )
+            })
+            .into(),
+    }
+}
+
+/// Solve both parts from a single parse of the input.  The parse gets its own isolated thread
+/// and timeout, and its time is reported on part 1.
+fn solve_shared(
+    solution: Day,
+    input: String,
+    timeout: Option<Duration>,
+) -> Vec<(u8, Result<PartRun, Failure>)> {
+    let parsed = isolate(format!("d{}", solution.day), timeout, move || {
+        let t = Instant::now();
+        let model = solution.parse(input);
+        (model, t.elapsed())
+    });
+
+    let (model, parse) = match parsed {
+        Ok(parsed) => parsed,
+        Err(failure) => return vec![(1, Err(failure.clone())), (2, Err(failure))],
+    };
+    let copy = solution
+        .share(&model)
+        .expect("only shared days are solved from one parse");
+
+    let p1 = isolate(format!("d{}p1", solution.day), timeout, move || {
+        let mut run = solution.run_parsed(1, copy);
+        run.parse = Some(parse);
+        run
+    });
+    let p2 = isolate(format!("d{}p2", solution.day), timeout, move || {
+        solution.run_parsed(2, model)
+    });
+
+    vec![(1, p1), (2, p2)]
+}
@@ -0,0 +1,297 @@
+//! Creating a new day's files from the template and registering it, so the runner can find it.
+
+use std::{fs, path::Path};
+
+use crate::args::ScaffoldArgs;
+
+/// The solution template.  `$AOC_DAY` and `$AOC_YEAR` (or `${AOC_DAY}` and `${AOC_YEAR}`) are
+/// replaced with the day and year.
+pub const TEMPLATE_FILE: &str = "templates/d.rs";
+
+/// Where the year registry lives.
+const LIB_FILE: &str = "src/lib.rs";
+
+/// Create a day's source file and empty example file, and register the day in its year's module.
+/// A year that doesn't exist yet gets a module of its own, registered in src/lib.rs.
+///
+/// An existing source file is only replaced with --force.  An existing example file is never
+/// touched, and a day that's already registered isn't registered twice.
+pub fn scaffold(args: &ScaffoldArgs) -> Result<(), String> {
+    let (year, day) = (args.year, args.day);
+    let year_dir = format!("src/y{year}");
+    let source_file = format!("{year_dir}/d{day}.rs");
+    let mod_file = format!("{year_dir}/mod.rs");
+    let example_file = aoc::inputs::example_path(year, day);
+
+    if Path::new(&source_file).exists() && !args.force {
+        return Err(format!(
+            "{source_file} already exists, use --force to overwrite it"
+        ));
+    }
+
+    let template = read(TEMPLATE_FILE)?;
+    fs::create_dir_all(&year_dir).map_err(|e| format!("can't create {year_dir}: {e}"))?;
+    write(&source_file, &render(&template, year, day))?;
+    println!("source created: {source_file}");
+
+    if Path::new(&example_file).exists() {
+        println!("example for day {day} already exists in {example_file}");
+    } else {
+        if let Some(dir) = Path::new(&example_file).parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("can't create {}: {e}", dir.display()))?;
+        }
+        write(&example_file, "")?;
+        println!("empty example created: {example_file}");
+    }
+
+    if Path::new(&mod_file).exists() {
+        let module = read(&mod_file)?;
+        let registered = add_day(&add_mod(&module, &format!("d{day}")), day)?;
+        if registered != module {
+            write(&mod_file, &registered)?;
+            println!("day {day} registered in {mod_file}");
+        }
+    } else {
+        write(&mod_file, &year_module(year, day))?;
+        println!("module created: {mod_file}");
+    }
+
+    let lib = read(LIB_FILE)?;
+    let registered = add_year(&add_mod(&lib, &format!("y{year}")), year)?;
+    if registered != lib {
+        write(LIB_FILE, &registered)?;
+        println!("year {year} registered in {LIB_FILE}");
+    }
+
+    Ok(())
+}
+
+/// Fill in the template's day and year.
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("${AOC_DAY}", &day.to_string())
+        .replace("$AOC_DAY", &day.to_string())
+        .replace("${AOC_YEAR}", &year.to_string())
+        .replace("$AOC_YEAR", &year.to_string())
+}
+
+/// A new year's module, with one day registered.
+fn year_module(year: u16, day: u8) -> String {
+    format!(
+        "\
+//! Solutions to Advent of Code {year}.
+//! https://adventofcode.com/{year}
+
+pub mod d{day};
+
+use crate::solution::Day;
+
+/// Every day with a solution, in order.  Add new days here after adding their module.
+pub const DAYS: &[Day] = &[Day::shared::<d{day}::D{day}>()];
+"
+    )
+}
+
+/// Add a `pub mod` line to a module's source, among the other `pub mod` lines in the order
+/// rustfmt keeps them.  Returns the source unchanged if the module is already declared.
+fn add_mod(source: &str, name: &str) -> String {
+    let mut lines: Vec<&str> = source.lines().collect();
+    if lines.iter().any(|&l| declared_mod(l) == Some(name)) {
+        return source.to_string();
+    }
+
+    let mods: Vec<usize> = (0..lines.len())
+        .filter(|&i| declared_mod(lines[i]).is_some())
+        .collect();
+    let at = mods
+        .iter()
+        .find(|&&i| declared_mod(lines[i]).is_some_and(|m| m > name))
+        .copied()
+        .or(mods.last().map(|i| i + 1))
+        .unwrap_or(0);
+
+    let line = format!("pub mod {name};");
+    lines.insert(at, &line);
+    lines.join("\n") + "\n"
+}
+
+/// The module a `pub mod` line declares.
+fn declared_mod(line: &str) -> Option<&str> {
+    line.strip_prefix("pub mod ")?.strip_suffix(';')
+}
+
+/// Add a day to a year module's `DAYS` registry, in day order.  Returns the source unchanged if
+/// the day is already registered.
+fn add_day(source: &str, day: u8) -> Result<String, String> {
+    // entries look like `Day::shared::<d10::D10>()`
+    let day_of = |entry: &str| -> Option<u16> {
+        let (_, rest) = entry.split_once("<d")?;
+        rest.split_once("::")?.0.parse().ok()
+    };
+    add_entry(
+        source,
+        "pub const DAYS: &[Day]",
+        format!("Day::shared::<d{day}::D{day}>()"),
+        day_of,
+    )
+}
+
+/// Add a year to the `YEARS` registry in src/lib.rs, in year order.  Returns the source unchanged
+/// if the year is already registered.
+fn add_year(source: &str, year: u16) -> Result<String, String> {
+    // entries look like `Year::new(2025, y2025::DAYS)`
+    let year_of = |entry: &str| -> Option<u16> {
+        let (_, rest) = entry.split_once("Year::new(")?;
+        rest.split_once(',')?.0.trim().parse().ok()
+    };
+    add_entry(
+        source,
+        "pub const YEARS: &[Year]",
+        format!("Year::new({year}, y{year}::DAYS)"),
+        year_of,
+    )
+}
+
+/// Add an entry to the registry array declared as `decl`, ordered by the key that `key_of` finds
+/// in each entry.  The array is rewritten the way rustfmt would lay it out.  Returns the source
+/// unchanged if an entry with the same key is already there.
+fn add_entry(
+    source: &str,
+    decl: &str,
+    entry: String,
+    key_of: impl Fn(&str) -> Option<u16>,
+) -> Result<String, String> {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with(decl))
+        .ok_or_else(|| format!("can't find `{decl}`"))?;
+    let end = (start..lines.len())
+        .find(|&i| lines[i].trim_end().ends_with("];"))
+        .ok_or_else(|| format!("can't find the end of `{decl}`"))?;
+
+    let registry = lines[start..=end].join("\n");
+    let items = registry
+        .split_once("= &[")
+        .and_then(|(_, rest)| rest.rsplit_once(']'))
+        .map(|(items, _)| items)
+        .ok_or_else(|| format!("`{decl}` isn't an array"))?;
+    let mut entries = split_entries(items);
+
+    let key = key_of(&entry);
+    if entries.iter().any(|e| key_of(e) == key) {
+        return Ok(source.to_string());
+    }
+    let at = entries
+        .iter()
+        .position(|e| key_of(e) > key)
+        .unwrap_or(entries.len());
+    entries.insert(at, entry);
+
+    // rustfmt keeps short arrays on one line
+    let one_line = format!("{decl} = &[{}];", entries.join(", "));
+    let replacement = if entries.join(", ").len() <= 60 && one_line.len() <= 100 {
+        one_line
+    } else {
+        let entries: Vec<String> = entries.iter().map(|e| format!("    {e},")).collect();
+        format!("{decl} = &[\n{}\n];", entries.join("\n"))
+    };
+
+    lines.splice(start..=end, [replacement]);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Split the items of an array on the commas that aren't inside parentheses.
+fn split_entries(items: &str) -> Vec<String> {
+    let mut entries = vec![];
+    let mut depth = 0;
+    let mut entry = String::new();
+    for c in items.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                entries.push(std::mem::take(&mut entry));
+                continue;
+            }
+            _ => {}
+        }
+        entry.push(c);
+    }
+    entries.push(entry);
+
+    entries
+        .into_iter()
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty())
+        .collect()
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))
+}
+
+fn write(path: &str, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("can't write {path}: {e}"))
+}
+
+#[cfg(test)]
+mod scaffold_tests {
+    use super::*;
+
+    const MODULE: &str = "\
+pub mod d1;
+pub mod d10;
+pub mod d3;
+
+use crate::solution::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::shared::<d1::D1>(),
+    Day::shared::<d3::D3>(),
+    Day::new::<d10::D10>(),
+];
+";
+
+    #[test]
+    fn render_test() {
+        let rendered = render("d$AOC_DAY ${AOC_DAY}_test $AOC_YEAR/day/$AOC_DAY", 2025, 4);
+        assert_eq!(rendered, "d4 4_test 2025/day/4");
+    }
+
+    #[test]
+    fn add_mod_test() {
+        let added = add_mod(MODULE, "d2");
+        assert!(added.starts_with("pub mod d1;\npub mod d10;\npub mod d2;\npub mod d3;\n"));
+        assert_eq!(add_mod(&added, "d2"), added);
+        assert!(add_mod(MODULE, "d4").contains("pub mod d3;\npub mod d4;\n"));
+        assert!(add_mod(MODULE, "d13").contains("pub mod d10;\npub mod d13;\npub mod d3;\n"));
+    }
+
+    #[test]
+    fn add_day_test() {
+        let added = add_day(MODULE, 2).unwrap();
+        assert!(
+            added.contains("<d1::D1>(),\n    Day::shared::<d2::D2>(),\n    Day::shared::<d3::D3>")
+        );
+        assert_eq!(add_day(&added, 2).unwrap(), added);
+        assert_eq!(add_day(MODULE, 10).unwrap(), MODULE);
+        assert!(add_day(MODULE, 12)
+            .unwrap()
+            .ends_with("<d12::D12>(),\n];\n"));
+    }
+
+    #[test]
+    fn add_year_test() {
+        let lib = "pub mod y2025;\n\npub const YEARS: &[Year] = &[Year::new(2025, y2025::DAYS)];\n";
+        let added = add_year(lib, 2024).unwrap();
+        assert!(added.ends_with("&[Year::new(2024, y2024::DAYS), Year::new(2025, y2025::DAYS)];\n"));
+        assert_eq!(add_year(&added, 2024).unwrap(), added);
+
+        let many = [2015, 2016, 2017, 2018]
+            .iter()
+            .fold(added, |lib, &y| add_year(&lib, y).unwrap());
+        assert!(many.contains("&[\n    Year::new(2015, y2015::DAYS),\n"));
+        assert!(many.ends_with("    Year::new(2025, y2025::DAYS),\n];\n"));
+    }
+}
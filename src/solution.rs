@@ -0,0 +1,208 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// What the day template's parts answer until they're solved.
+pub const STUB_ANSWER: &str = "incomplete";
+
+/// A solution to one day's puzzle.
+///
+/// Each day module implements this on a unit struct named after the module (`d1::D1`, etc) so
+/// the runner can find it through its year's registry, like [`crate::y2025::DAYS`].
+pub trait Solution {
+    /// The day this solution solves.
+    const DAY: u8;
+
+    /// The parsed puzzle input, shared by both parts.
+    type Model: Send + 'static;
+
+    /// The answer produced by each part.
+    type Answer: Display;
+
+    fn parse(input: String) -> Self::Model;
+    fn part1(model: Self::Model) -> Self::Answer;
+    fn part2(model: Self::Model) -> Self::Answer;
+}
+
+/// A parsed model with its type erased.  Only the [`Day`] that produced it can solve it.
+pub struct Model(Box<dyn Any + Send>);
+
+/// A registry entry for a single day, with the solution's types erased so days with different
+/// models and answers can live in one list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    parse: fn(String) -> Model,
+    solve: fn(u8, Model) -> String,
+    clone: Option<fn(&Model) -> Model>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            parse: parse::<S>,
+            solve: solve::<S>,
+            clone: None,
+        }
+    }
+
+    /// Register a day whose model can be cloned, so both parts can share one parse.
+    pub const fn shared<S: Solution>() -> Self
+    where
+        S::Model: Clone,
+    {
+        Self {
+            clone: Some(clone::<S>),
+            ..Self::new::<S>()
+        }
+    }
+
+    pub fn parse(&self, input: String) -> Model {
+        (self.parse)(input)
+    }
+
+    /// Solve the given part, returning the answer as a string.  Panics if part is not 1 or 2.
+    pub fn solve(&self, part: u8, model: Model) -> String {
+        (self.solve)(part, model)
+    }
+
+    /// Returns true if this day was registered with [`Day::shared`].
+    pub fn is_shared(&self) -> bool {
+        self.clone.is_some()
+    }
+
+    /// Copy a model so it can be solved more than once.  Returns None if this day wasn't
+    /// registered with [`Day::shared`].
+    pub fn share(&self, model: &Model) -> Option<Model> {
+        self.clone.map(|clone| clone(model))
+    }
+
+    /// Parse the input and solve the given part, timing each step.
+    pub fn run(&self, part: u8, input: String) -> PartRun {
+        let t = Instant::now();
+        let model = self.parse(input);
+        let parse = t.elapsed();
+
+        let mut run = self.run_parsed(part, model);
+        run.parse = Some(parse);
+        run
+    }
+
+    /// Solve the given part using an already-parsed model, timing the solve.  The returned run
+    /// has no parse time.
+    pub fn run_parsed(&self, part: u8, model: Model) -> PartRun {
+        let t = Instant::now();
+        let answer = self.solve(part, model);
+        let solve = t.elapsed();
+
+        PartRun {
+            day: self.day,
+            part,
+            answer,
+            parse: None,
+            solve,
+        }
+    }
+
+    /// Run both parts.  If this day was registered with [`Day::shared`], the input is parsed
+    /// once and the parse time is reported on part 1 only.  Otherwise each part parses the input
+    /// itself.
+    pub fn run_both(&self, input: String) -> [PartRun; 2] {
+        let Some(clone) = self.clone else {
+            return [self.run(1, input.clone()), self.run(2, input)];
+        };
+
+        let t = Instant::now();
+        let model = self.parse(input);
+        let parse = t.elapsed();
+
+        let mut p1 = self.run_parsed(1, clone(&model));
+        p1.parse = Some(parse);
+        let p2 = self.run_parsed(2, model);
+
+        [p1, p2]
+    }
+}
+
+/// The answer and timings from running one part.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// Time spent parsing, or None if the model was shared with another part.
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+}
+
+/// Format a duration with a unit suited to its size, eg "1.234ms".
+pub fn format_duration(time: Duration) -> String {
+    let t = if time.as_nanos() > 1_000_000_000 {
+        ((time.as_millis() as f32) / 1000.0, "s")
+    } else if time.as_nanos() > 1_000_000 {
+        ((time.as_micros() as f32) / 1000.0, "ms")
+    } else if time.as_nanos() > 1_000 {
+        ((time.as_nanos() as f32) / 1000.0, "μs")
+    } else {
+        (time.as_nanos() as f32, "ns")
+    };
+
+    format!("{}{}", t.0, t.1)
+}
+
+fn parse<S: Solution>(input: String) -> Model {
+    Model(Box::new(S::parse(input)))
+}
+
+fn solve<S: Solution>(part: u8, model: Model) -> String {
+    let model = *model
+        .0
+        .downcast::<S::Model>()
+        .expect("model was parsed by a different day");
+    match part {
+        1 => S::part1(model).to_string(),
+        2 => S::part2(model).to_string(),
+        _ => panic!("part must be 1 or 2"),
+    }
+}
+
+fn clone<S: Solution>(model: &Model) -> Model
+where
+    S::Model: Clone,
+{
+    let model = model
+        .0
+        .downcast_ref::<S::Model>()
+        .expect("model was parsed by a different day");
+    Model(Box::new(model.clone()))
+}
+
+/// Find a day in the registry.
+pub fn find(days: &[Day], day: u8) -> Option<&Day> {
+    days.iter().find(|d| d.day == day)
+}
+
+/// A registry entry for one year's event, holding every day solved that year.
+#[derive(Clone, Copy)]
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub const fn new(year: u16, days: &'static [Day]) -> Self {
+        Self { year, days }
+    }
+
+    /// Look up one of this year's days.
+    pub fn day(&self, day: u8) -> Option<&Day> {
+        find(self.days, day)
+    }
+}
+
+pub fn find_year(years: &[Year], year: u16) -> Option<&Year> {
+    years.iter().find(|y| y.year == year)
+}
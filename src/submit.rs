@@ -0,0 +1,361 @@
+//! Submitting answers to the Advent of Code site, and remembering what was guessed.
+//!
+//! Every verdict on a guess is kept in a per-day history file like `guesses/2025/d1`, so an
+//! answer that's known to be wrong, or that contradicts a "too high" or "too low" hint, can be
+//! caught before it costs a submission.
+//!
+//! ```text
+//! p1 high 2000
+//! p1 low 900
+//! p1 correct 1191
+//! ```
+
+use std::{fmt::Display, fs, io, path::Path, time::Duration};
+
+use crate::{fetch::Site, solution::STUB_ANSWER};
+
+/// The path of a day's guess history, relative to the root of the repo.
+pub fn guesses_path(year: u16, day: u8) -> String {
+    format!("guesses/{year}/d{day}")
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// Another answer was submitted too recently, and this one wasn't checked.
+    RateLimited(Duration),
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+    /// A response that doesn't look like any of the others, with its text.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// The word used for this verdict in the guess history, if it's a verdict on the answer.
+    fn key(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("high"),
+            Verdict::TooLow => Some("low"),
+            Verdict::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Verdict::Correct),
+            "high" => Some(Verdict::TooHigh),
+            "low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            Verdict::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+            Verdict::Unknown(text) => write!(f, "unrecognized response: {text}"),
+        }
+    }
+}
+
+/// Every recorded guess for one day, in the order they were made.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Guesses(Vec<(u8, Verdict, String)>);
+
+impl Guesses {
+    /// Load a guess history.  A missing file is the same as an empty one.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut guesses = Self::default();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let err = || format!("line {}: expected a line like `p1 high 12345`", i + 1);
+            let mut fields = line.splitn(3, ' ');
+            let part = match fields.next() {
+                Some("p1") => 1,
+                Some("p2") => 2,
+                _ => return Err(err()),
+            };
+            let verdict = fields.next().and_then(Verdict::from_key).ok_or_else(err)?;
+            let answer = fields.next().ok_or_else(err)?;
+            guesses.record(part, answer, verdict);
+        }
+        Ok(guesses)
+    }
+
+    /// Remember the verdict on a guess.  Verdicts that say nothing about the answer (like being
+    /// rate limited) aren't recorded.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        if verdict.key().is_some() {
+            self.0.push((part, verdict, answer.to_string()));
+        }
+    }
+
+    /// The part's correct answer, if it's been found.
+    pub fn correct(&self, part: u8) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(p, verdict, _)| *p == part && *verdict == Verdict::Correct)
+            .map(|(_, _, answer)| answer.as_str())
+    }
+
+    /// Check whether an answer is worth submitting, given the guesses so far.  Returns why not,
+    /// if it isn't.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        if answer.trim().is_empty() {
+            return Err("the answer is empty".to_string());
+        }
+        if answer == STUB_ANSWER {
+            return Err(format!(
+                "{answer:?} is the template's placeholder, part {part} isn't solved yet"
+            ));
+        }
+
+        if let Some(correct) = self.correct(part) {
+            return Err(if correct == answer {
+                format!("{answer} was already accepted")
+            } else {
+                format!("part {part} is already solved, the answer was {correct}")
+            });
+        }
+
+        let number = answer.parse::<i128>().ok();
+        for (_, verdict, guess) in self.0.iter().filter(|(p, _, _)| *p == part) {
+            if guess == answer {
+                return Err(format!("{answer} was already guessed, and was {verdict}"));
+            }
+
+            let hint = number.zip(guess.parse::<i128>().ok());
+            match (verdict, hint) {
+                (Verdict::TooHigh, Some((n, high))) if n >= high => {
+                    return Err(format!("{answer} can't be right, {guess} was too high"))
+                }
+                (Verdict::TooLow, Some((n, low))) if n <= low => {
+                    return Err(format!("{answer} can't be right, {guess} was too low"))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Guesses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (part, verdict, answer) in &self.0 {
+            if let Some(key) = verdict.key() {
+                writeln!(f, "p{part} {key} {answer}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submit an answer and return the site's verdict.
+pub fn submit_answer(
+    site: &Site,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, String> {
+    let body = format!("level={part}&answer={}", url_encode(answer));
+    let response = site.request("POST", &format!("/{year}/day/{day}/answer"), Some(&body))?;
+    if response.status != 200 {
+        return Err(format!("unexpected HTTP status {}", response.status));
+    }
+
+    Ok(parse_verdict(&response.body))
+}
+
+/// Work out the verdict from the text of the response page.
+fn parse_verdict(html: &str) -> Verdict {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited(wait_time(&text).unwrap_or_default())
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// The text inside the page's `<article>`, or the whole page if there isn't one, without tags
+/// and with whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article)
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Find a wait like "You have 1m 23s left to wait" in the text.
+fn wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    let mut secs = 0;
+    for amount in wait.split_whitespace() {
+        let split = amount.find(|c: char| !c.is_ascii_digit())?;
+        let (n, unit) = amount.split_at(split);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Percent-encode everything but unreserved characters, for form data.
+fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod submit_tests {
+    use super::*;
+    use crate::http::{stand_in, TcpHttp};
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn parse_verdict_test() {
+        let correct = page("That's the right answer!  You are <em>one gold star</em> closer.");
+        assert_eq!(parse_verdict(&correct), Verdict::Correct);
+
+        let high = page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.");
+        assert_eq!(parse_verdict(&high), Verdict::TooHigh);
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(parse_verdict(&low), Verdict::TooLow);
+        let wrong = page("That's not the right answer.  If you're stuck...");
+        assert_eq!(parse_verdict(&wrong), Verdict::Wrong);
+
+        let recent = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.");
+        assert_eq!(
+            parse_verdict(&recent),
+            Verdict::RateLimited(Duration::from_secs(83))
+        );
+
+        let level =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_verdict(&level), Verdict::WrongLevel);
+        assert!(matches!(parse_verdict("huh"), Verdict::Unknown(_)));
+    }
+
+    #[test]
+    fn guesses_test() {
+        let guesses =
+            Guesses::parse("p1 high 2000\np1 low 900\np1 wrong abc\np2 correct 42\n").unwrap();
+        assert_eq!(Guesses::parse(&guesses.to_string()).unwrap(), guesses);
+
+        assert!(guesses.check(1, "1500").is_ok());
+        assert!(guesses.check(1, "2000").is_err());
+        assert!(guesses.check(1, "2001").is_err());
+        assert!(guesses.check(1, "900").is_err());
+        assert!(guesses.check(1, "12").is_err());
+        assert!(guesses.check(1, "abc").is_err());
+        assert!(guesses.check(1, "abd").is_ok());
+        assert!(guesses.check(2, "43").is_err());
+        assert!(guesses.check(1, "").is_err());
+        assert!(guesses.check(1, " \n").is_err());
+        assert!(guesses.check(1, STUB_ANSWER).is_err());
+        assert_eq!(guesses.correct(2), Some("42"));
+
+        assert!(Guesses::parse("p3 high 1").is_err());
+        assert!(Guesses::parse("p1 maybe 1").is_err());
+    }
+
+    #[test]
+    fn submit_answer_test() {
+        let (base_url, requests) = stand_in::serve(vec![(200, &page("That's the right answer!"))]);
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let site = Site {
+            base_url,
+            session: Some("abc123".to_string()),
+            http: Box::new(TcpHttp),
+            stamp_file: dir.join(".last_request"),
+            min_interval: Duration::ZERO,
+        };
+
+        assert_eq!(
+            submit_answer(&site, 2025, 3, 2, "a b"),
+            Ok(Verdict::Correct)
+        );
+        let request = &requests.lock().unwrap()[0];
+        assert!(request.starts_with("POST /2025/day/3/answer "));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=a%20b"));
+    }
+}
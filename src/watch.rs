@@ -0,0 +1,335 @@
+//! Running a day again whenever its files change, and showing how the answers changed.
+//!
+//! The day's files are polled, so files created after the watch started (like a new example) are
+//! picked up too.  Inputs, examples, and answers are simply read again, but a change to the
+//! solution's source means rebuilding the runner: it's rebuilt with `cargo build` and started
+//! over in place of the old one, with the last run's answers handed down so the diff still
+//! works.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{self, Write},
+    os::unix::process::CommandExt,
+    path::Path,
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc::{
+    answers::{self, Answers},
+    inputs,
+    solution::Day,
+};
+use termion::{clear, cursor};
+
+use crate::{
+    args::Args,
+    runner::{Answered, Runner},
+};
+
+/// How often the files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Set when the runner restarts itself after a rebuild, to the file holding the last run's
+/// answers.
+const PREVIOUS_VAR: &str = "AOC_WATCH_PREVIOUS";
+
+/// The size and modification time of each watched file, or `None` if it doesn't exist (yet).
+type Snapshot = BTreeMap<String, Option<(u64, SystemTime)>>;
+
+/// Run the day, then run it again every time its files change, until interrupted.
+pub fn watch(runner: &mut Runner, args: &Args, solution: &Day) -> ! {
+    let (year, day) = (args.year, solution.day);
+    let source = format!("src/y{year}/d{day}.rs");
+    let mut previous = take_previous();
+    // found now, since once the runner's rebuilt its path looks like it's been deleted
+    let exe = env::current_exe();
+
+    loop {
+        if termion::is_tty(&io::stdout()) {
+            print!("{}{}", clear::All, cursor::Goto(1, 1));
+        }
+        runner.printer.header();
+
+        // taken before running, so files saved while it runs are caught
+        let mut files = snapshot(&watched(args, &source, day));
+        // the answers may have been edited since the last run
+        if let Ok(answers) = Answers::load_year(".", year) {
+            runner.answers = answers;
+        }
+        let answered = match crate::input_files(args, year, day) {
+            Ok(input_files) => runner.run_files(solution, args.part, &input_files),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                Answered::new()
+            }
+        };
+        if let Some(previous) = &previous {
+            print_changes(day, previous, &answered);
+        }
+        if runner.save {
+            if let Err(e) = runner.answers.save_year(".", year) {
+                eprintln!("Error: couldn't save answers: {e}");
+            }
+            // saving rewrites the answers files, which isn't a reason to run again
+            let answers_path = answers::answers_path(year);
+            let sidecar = format!(".{}", answers::SIDECAR_EXTENSION);
+            let saved: Vec<String> = watched(args, &source, day)
+                .into_iter()
+                .filter(|file| *file == answers_path || file.ends_with(&sidecar))
+                .collect();
+            files.extend(snapshot(&saved));
+        }
+
+        eprintln!(
+            "\nwatching {} files for changes, press ctrl-c to stop",
+            files.len()
+        );
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let now = snapshot(&watched(args, &source, day));
+            if now == files {
+                continue;
+            }
+
+            let rebuild = now.get(&source) != files.get(&source);
+            files = now;
+            if !rebuild {
+                break;
+            }
+            // only returns if the build failed, in which case there's nothing new to run
+            eprintln!("\n{source} changed, rebuilding");
+            match &exe {
+                Ok(exe) => restart(exe, &answered),
+                Err(e) => eprintln!("Error: can't find the runner to restart it: {e}"),
+            }
+        }
+
+        previous = Some(answered);
+    }
+}
+
+/// Every file the day's runs depend on: its source, puzzle input, examples (along with their
+/// answers), the year's answers, and any input files given on the command line.
+fn watched(args: &Args, source: &str, day: u8) -> Vec<String> {
+    let mut files = vec![
+        source.to_string(),
+        inputs::input_path(args.year, day),
+        answers::answers_path(args.year),
+    ];
+
+    let dir = inputs::example_dir(args.year);
+    if let Ok(entries) = fs::read_dir(&dir) {
+        let name = format!("d{day}");
+        for entry in entries.filter_map(|e| e.ok()) {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let (base, _) = file_name.split_once(['-', '.']).unwrap_or((&file_name, ""));
+            if base == name {
+                files.push(format!("{dir}/{file_name}"));
+            }
+        }
+    }
+
+    // patterns are expanded again every time, to pick up new matches
+    files.extend(crate::input_files(args, args.year, day).unwrap_or_default());
+    files.sort();
+    files.dedup();
+    files
+}
+
+fn snapshot(files: &[String]) -> Snapshot {
+    files
+        .iter()
+        .map(|file| {
+            let stamp = fs::metadata(file)
+                .ok()
+                .map(|m| (m.len(), m.modified().unwrap_or(SystemTime::UNIX_EPOCH)));
+            (file.clone(), stamp)
+        })
+        .collect()
+}
+
+/// Print how each answer differs from the one the previous run gave.
+fn print_changes(day: u8, previous: &Answered, answered: &Answered) {
+    let describe = |answer: Option<&Result<String, String>>| match answer {
+        None => "not run".to_string(),
+        Some(Ok(answer)) => answer.clone(),
+        Some(Err(e)) => format!("({e})"),
+    };
+
+    let mut keys: Vec<&(String, u8)> = previous.keys().chain(answered.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut changes = vec![];
+    for key in keys {
+        let (before, after) = (previous.get(key), answered.get(key));
+        if before == after {
+            continue;
+        }
+
+        let (input_file, part) = key;
+        let (before, after) = (describe(before), describe(after));
+        if before.contains('\n') || after.contains('\n') {
+            changes.push(format!("  d{day}p{part} {input_file}:"));
+            changes.extend(line_diff(&before, &after));
+        } else {
+            changes.push(format!("  d{day}p{part} {input_file}: {before} -> {after}"));
+        }
+    }
+
+    if changes.is_empty() {
+        eprintln!("\nno answers changed since the last run");
+    } else {
+        eprintln!("\nchanged since the last run:");
+        for change in changes {
+            eprintln!("{change}");
+        }
+    }
+}
+
+/// The lines that differ between two multi-line answers, compared line by line.
+fn line_diff(before: &str, after: &str) -> Vec<String> {
+    let (before, after): (Vec<&str>, Vec<&str>) =
+        (before.lines().collect(), after.lines().collect());
+    let mut diff = vec![];
+    for i in 0..before.len().max(after.len()) {
+        let (old, new) = (before.get(i), after.get(i));
+        if old == new {
+            continue;
+        }
+        if let Some(old) = old {
+            diff.push(format!("    - {old}"));
+        }
+        if let Some(new) = new {
+            diff.push(format!("    + {new}"));
+        }
+    }
+    diff
+}
+
+/// Rebuild the runner and start it over with the same arguments, handing down the last run's
+/// answers.  Only returns if that couldn't be done.
+fn restart(exe: &Path, answered: &Answered) {
+    let mut build = Command::new("cargo");
+    build.args(["build", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    match build.status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            eprintln!("the build failed, waiting for the next change");
+            return;
+        }
+        Err(e) => {
+            eprintln!("Error: can't run cargo: {e}");
+            return;
+        }
+    }
+
+    let previous = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+    if let Err(e) = fs::write(&previous, encode(answered)) {
+        eprintln!("Error: can't save the answers for the next run: {e}");
+    }
+    let _ = io::stdout().flush();
+
+    // exec only returns on failure
+    let e = Command::new(exe)
+        .args(env::args_os().skip(1))
+        .env(PREVIOUS_VAR, &previous)
+        .exec();
+    eprintln!("Error: can't restart the runner: {e}");
+}
+
+/// The answers handed down from before a restart, if there was one.
+fn take_previous() -> Option<Answered> {
+    let path = env::var_os(PREVIOUS_VAR)?;
+    let encoded = fs::read_to_string(&path).ok();
+    let _ = fs::remove_file(&path);
+    encoded.map(|encoded| decode(&encoded))
+}
+
+/// One answer per line, as `part<tab>input file<tab>ok or err<tab>answer`, with tabs,
+/// newlines, and backslashes escaped.
+fn encode(answered: &Answered) -> String {
+    let escape = |s: &str| {
+        s.replace('\\', "\\\\")
+            .replace('\t', "\\t")
+            .replace('\n', "\\n")
+    };
+    answered
+        .iter()
+        .map(|((input_file, part), answer)| {
+            let (kind, text) = match answer {
+                Ok(answer) => ("ok", answer),
+                Err(e) => ("err", e),
+            };
+            format!("{part}\t{}\t{kind}\t{}\n", escape(input_file), escape(text))
+        })
+        .collect()
+}
+
+fn decode(encoded: &str) -> Answered {
+    let unescape = |s: &str| {
+        let mut unescaped = String::with_capacity(s.len());
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                unescaped.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('t') => unescaped.push('\t'),
+                Some('n') => unescaped.push('\n'),
+                Some(c) => unescaped.push(c),
+                None => {}
+            }
+        }
+        unescaped
+    };
+
+    encoded
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(4, '\t');
+            let part = fields.next()?.parse().ok()?;
+            let input_file = unescape(fields.next()?);
+            let answer = match (fields.next()?, unescape(fields.next()?)) {
+                ("ok", answer) => Ok(answer),
+                (_, e) => Err(e),
+            };
+            Some(((input_file, part), answer))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod watch_tests {
+    use super::*;
+
+    #[test]
+    fn encode_test() {
+        let mut answered = Answered::new();
+        answered.insert(("input/2025/d1".to_string(), 1), Ok("1191".to_string()));
+        answered.insert(
+            ("examples/2025/d1-a\tb".to_string(), 2),
+            Ok("#..\n.#\\".to_string()),
+        );
+        answered.insert(
+            ("input/2025/d1".to_string(), 2),
+            Err("timed out after 1s".to_string()),
+        );
+        assert_eq!(decode(&encode(&answered)), answered);
+    }
+
+    #[test]
+    fn line_diff_test() {
+        assert_eq!(
+            line_diff("#.\n.#\n##", "#.\n..\n"),
+            vec!["    - .#", "    + ..", "    - ##"]
+        );
+    }
+}
@@ -1,10 +1,12 @@
 //! A solution to day 1 year 2025.
 //! https://adventofcode.com/2025/day/1
 
+use crate::solution::Solution;
+
 type Model = Vec<Turn>;
 type Answer = i64;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Turn {
     Left(i64),
     Right(i64),
@@ -60,30 +62,22 @@ pub fn part2(model: Model) -> Answer {
     zc
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = include_str!("../input/d1");
-    const EXAMPLE: &str = include_str!("../examples/d1");
+pub struct D1;
 
-    #[test]
-    fn d1p1_example_test() {
-        assert_eq!(part1(parse(EXAMPLE.to_string())), 3);
-    }
+impl Solution for D1 {
+    const DAY: u8 = 1;
+    type Model = Model;
+    type Answer = Answer;
 
-    #[test]
-    fn d1p1_input_test() {
-        assert_eq!(part1(parse(INPUT.to_string())), 1191);
+    fn parse(input: String) -> Model {
+        parse(input)
     }
 
-    #[test]
-    fn d1p2_example_test() {
-        assert_eq!(part2(parse(EXAMPLE.to_string())), 6);
+    fn part1(model: Model) -> Answer {
+        part1(model)
     }
 
-    #[test]
-    fn d1p2_input_test() {
-        assert_eq!(part2(parse(INPUT.to_string())), 6858);
+    fn part2(model: Model) -> Answer {
+        part2(model)
     }
 }
@@ -2,6 +2,7 @@
 //! https://adventofcode.com/2025/day/2
 
 use crate::digits::count::digit_count;
+use crate::solution::Solution;
 use std::ops::RangeInclusive;
 
 type Model = Vec<(i64, i64)>;
@@ -90,30 +91,22 @@ pub fn part2(model: Model) -> Answer {
     sum
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct D2;
 
-    const INPUT: &str = include_str!("../input/d2");
-    const EXAMPLE: &str = include_str!("../examples/d2");
+impl Solution for D2 {
+    const DAY: u8 = 2;
+    type Model = Model;
+    type Answer = Answer;
 
-    #[test]
-    fn d2p1_example_test() {
-        assert_eq!(part1(parse(EXAMPLE.to_string())), 1227775554);
+    fn parse(input: String) -> Model {
+        parse(input)
     }
 
-    #[test]
-    fn d2p1_input_test() {
-        assert_eq!(part1(parse(INPUT.to_string())), 28846518423);
+    fn part1(model: Model) -> Answer {
+        part1(model)
     }
 
-    #[test]
-    fn d2p2_example_test() {
-        assert_eq!(part2(parse(EXAMPLE.to_string())), 4174379265);
-    }
-
-    #[test]
-    fn d2p2_input_test() {
-        assert_eq!(part2(parse(INPUT.to_string())), 31578210022);
+    fn part2(model: Model) -> Answer {
+        part2(model)
     }
 }
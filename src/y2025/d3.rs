@@ -7,6 +7,7 @@ use itertools::Itertools as _;
 use num_bigint::BigInt;
 
 use crate::digits::crud::{get_digit, set_digit};
+use crate::solution::Solution;
 
 type Model = Vec<Vec<u8>>;
 type Answer = u64;
@@ -321,33 +322,22 @@ pub fn part2(model: Model) -> Answer {
     sum
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct D3;
 
-    const INPUT: &str = include_str!("../input/d3");
-    const EXAMPLE: &str = include_str!("../examples/d3");
+impl Solution for D3 {
+    const DAY: u8 = 3;
+    type Model = Model;
+    type Answer = Answer;
 
-    #[test]
-    fn d3p1_example_test() {
-        assert_eq!(part1(parse(EXAMPLE.to_string())), 357);
+    fn parse(input: String) -> Model {
+        parse(input)
     }
 
-    #[test]
-    fn d3p1_input_test() {
-        assert_eq!(part1(parse(INPUT.to_string())), 16993);
+    fn part1(model: Model) -> Answer {
+        part1(model)
     }
 
-    #[test]
-    fn d3p2_example_test() {
-        assert_eq!(part2(parse(EXAMPLE.to_string())), 3121910778619);
+    fn part2(model: Model) -> Answer {
+        part2(model)
     }
-
-    // #[test]
-    // fn d3p2_input_test() {
-    //     assert_eq!(
-    //         part2(INPUT.to_string()),
-    //         "put part 2 final answer here"
-    //     );
-    // }
 }
@@ -0,0 +1,39 @@
+//! A solution to day 5 year 2025.
+//! https://adventofcode.com/2025/day/5
+
+use crate::solution::Solution;
+
+type Model = u8;
+type Answer = String;
+
+pub fn parse(input: String) -> Model {
+    0
+}
+
+pub fn part1(model: Model) -> Answer {
+    "incomplete".to_string()
+}
+
+pub fn part2(model: Model) -> Answer {
+    "incomplete".to_string()
+}
+
+pub struct D5;
+
+impl Solution for D5 {
+    const DAY: u8 = 5;
+    type Model = Model;
+    type Answer = Answer;
+
+    fn parse(input: String) -> Model {
+        parse(input)
+    }
+
+    fn part1(model: Model) -> Answer {
+        part1(model)
+    }
+
+    fn part2(model: Model) -> Answer {
+        part2(model)
+    }
+}
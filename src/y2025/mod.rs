@@ -0,0 +1,33 @@
+//! Solutions to Advent of Code 2025.
+//! https://adventofcode.com/2025
+
+pub mod d1;
+pub mod d10;
+pub mod d11;
+pub mod d12;
+pub mod d2;
+pub mod d3;
+pub mod d4;
+pub mod d5;
+pub mod d6;
+pub mod d7;
+pub mod d8;
+pub mod d9;
+
+use crate::solution::Day;
+
+/// Every day with a solution, in order.  Add new days here after adding their module.
+pub const DAYS: &[Day] = &[
+    Day::shared::<d1::D1>(),
+    Day::shared::<d2::D2>(),
+    Day::shared::<d3::D3>(),
+    Day::shared::<d4::D4>(),
+    Day::shared::<d5::D5>(),
+    Day::shared::<d6::D6>(),
+    Day::shared::<d7::D7>(),
+    Day::shared::<d8::D8>(),
+    Day::shared::<d9::D9>(),
+    Day::shared::<d10::D10>(),
+    Day::shared::<d11::D11>(),
+    Day::shared::<d12::D12>(),
+];
@@ -0,0 +1,254 @@
+//! One test per example file and part, and per puzzle input and part, found at run time.
+//!
+//! Every `examples/<year>/dN*` file with a sidecar answers file (like `examples/2025/d3.answers`)
+//! gets a test for each part the sidecar has an answer for, named like `y2025::d3p1_example`.  An
+//! example that's missing or empty, or whose day has no solution yet, is reported as ignored
+//! instead of failing.
+//!
+//! Every solved day also gets a test for each part with a known answer for its puzzle input,
+//! named like `y2025::d3p1_input`.  Inputs aren't checked in, so one that hasn't been downloaded
+//! is reported as ignored, with the command to fetch it.
+//!
+//! The tests are generated, so this replaces the built-in test harness with just enough of one
+//! to work with `cargo test` and `cargo nextest`: name filters, `--skip`, `--exact`,
+//! `--ignored`, `--include-ignored`, and `--list`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::exit,
+    time::Duration,
+};
+
+use aoc::{
+    answers::{self, Answers},
+    inputs,
+    isolate::isolate,
+    solution, YEARS,
+};
+
+/// How long one part gets before its test fails.  A stuck solution can't be stopped, but it
+/// doesn't hold up the rest.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One generated test.
+struct Case {
+    name: String,
+    year: u16,
+    day: u8,
+    part: u8,
+    /// The example or puzzle input to solve.
+    input: PathBuf,
+    expected: String,
+    /// Why the test can't run, if it can't.
+    skip: Option<String>,
+}
+
+/// The options libtest takes that matter here.  Everything else is accepted and ignored.
+#[derive(Default)]
+struct Options {
+    filters: Vec<String>,
+    skip: Vec<String>,
+    exact: bool,
+    list: bool,
+    ignored: bool,
+    include_ignored: bool,
+}
+
+fn main() {
+    let options = options(std::env::args().skip(1));
+    let all = cases();
+    let total = all.len();
+    let matches = |case: &Case, filter: &String| match options.exact {
+        true => case.name == *filter,
+        false => case.name.contains(filter.as_str()),
+    };
+    let cases: Vec<Case> = all
+        .into_iter()
+        .filter(|case| {
+            options.filters.is_empty() || options.filters.iter().any(|f| matches(case, f))
+        })
+        .filter(|case| !options.skip.iter().any(|f| matches(case, f)))
+        .collect();
+
+    if options.list {
+        // nextest lists with --ignored to find out which tests are ignored
+        for case in cases
+            .iter()
+            .filter(|case| !options.ignored || case.skip.is_some())
+        {
+            println!("{}: test", case.name);
+        }
+        return;
+    }
+
+    let plural = if cases.len() == 1 { "" } else { "s" };
+    println!("\nrunning {} test{plural}", cases.len());
+    let (mut passed, mut failed, mut ignored) = (vec![], vec![], 0);
+    for case in &cases {
+        let run = match &case.skip {
+            Some(_) => options.ignored || options.include_ignored,
+            None => !options.ignored,
+        };
+        if !run {
+            match &case.skip {
+                Some(reason) => println!("test {} ... ignored, {reason}", case.name),
+                None => println!("test {} ... ignored", case.name),
+            }
+            ignored += 1;
+            continue;
+        }
+
+        match run_case(case) {
+            Ok(()) => {
+                println!("test {} ... ok", case.name);
+                passed.push(&case.name);
+            }
+            Err(e) => {
+                println!("test {} ... FAILED", case.name);
+                failed.push((&case.name, e));
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        println!("\nfailures:\n");
+        for (name, e) in &failed {
+            println!("---- {name} ----\n{e}\n");
+        }
+    }
+    let result = if failed.is_empty() { "ok" } else { "FAILED" };
+    println!(
+        "\ntest result: {result}. {} passed; {} failed; {ignored} ignored; 0 measured; {} filtered out\n",
+        passed.len(),
+        failed.len(),
+        total - cases.len(),
+    );
+
+    // exit rather than return, so a solution that timed out can't keep the process alive
+    exit(if failed.is_empty() { 0 } else { 101 });
+}
+
+fn options(mut args: impl Iterator<Item = String>) -> Options {
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exact" => options.exact = true,
+            "--list" => options.list = true,
+            "--ignored" => options.ignored = true,
+            "--include-ignored" => options.include_ignored = true,
+            "--skip" => options.skip.extend(args.next()),
+            // options with a value
+            "--format" | "--color" | "--test-threads" | "--logfile" | "-Z" => {
+                args.next();
+            }
+            arg if arg.starts_with('-') => {}
+            _ => options.filters.push(arg),
+        }
+    }
+    options
+}
+
+/// Every example and puzzle input and part with an answer, in every year.
+fn cases() -> Vec<Case> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut cases = vec![];
+
+    for year in YEARS {
+        let answers = Answers::load_year(root, year.year).unwrap_or_else(|e| {
+            eprintln!("can't load {} answers: {e}", year.year);
+            exit(101);
+        });
+        // a year without an examples directory can still have input tests
+        let entries = fs::read_dir(root.join(inputs::example_dir(year.year)));
+        let mut examples: Vec<PathBuf> = entries
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|ext| ext == answers::SIDECAR_EXTENSION)
+            })
+            .map(|path| path.with_extension(""))
+            .collect();
+        examples.sort();
+
+        for example in examples {
+            let Some(day) = answers::example_day(&example) else {
+                continue;
+            };
+            let name = answers::input_name(day, &example.to_string_lossy());
+            let empty = fs::metadata(&example).map_or(true, |m| m.len() == 0);
+            let skip = if year.day(day).is_none() {
+                Some("no solution yet".to_string())
+            } else if empty {
+                Some("the example is missing or empty".to_string())
+            } else {
+                None
+            };
+
+            for part in 1..=2 {
+                let Some(expected) = answers.get(day, &name, part) else {
+                    continue;
+                };
+                cases.push(Case {
+                    name: format!("y{}::d{day}p{part}_{}", year.year, name.replace('-', "_")),
+                    year: year.year,
+                    day,
+                    part,
+                    input: example.clone(),
+                    expected: expected.to_string(),
+                    skip: skip.clone(),
+                });
+            }
+        }
+
+        for day in year.days {
+            let input = root.join(inputs::input_path(year.year, day.day));
+            let skip = (!input.is_file())
+                .then(|| format!("no input, download it with `aoc fetch -d {}`", day.day));
+            for part in 1..=2 {
+                let Some(expected) = answers.get(day.day, "input", part) else {
+                    continue;
+                };
+                cases.push(Case {
+                    name: format!("y{}::d{}p{part}_input", year.year, day.day),
+                    year: year.year,
+                    day: day.day,
+                    part,
+                    input: input.clone(),
+                    expected: expected.to_string(),
+                    skip: skip.clone(),
+                });
+            }
+        }
+    }
+
+    cases
+}
+
+/// Solve the case's part, and compare the answer with the expected one.
+fn run_case(case: &Case) -> Result<(), String> {
+    let input = fs::read_to_string(&case.input)
+        .map_err(|e| format!("can't read {}: {e}", case.input.display()))?;
+    let solution = *solution::find_year(YEARS, case.year)
+        .and_then(|year| year.day(case.day))
+        .ok_or("no solution")?;
+    let part = case.part;
+
+    let answer = isolate(case.name.clone(), Some(TIMEOUT), move || {
+        solution.run(part, input).answer
+    })
+    .map_err(|failure| format!("the solution {failure}"))?;
+
+    if answer == case.expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "wrong answer for {}\n  expected: {}\n       got: {answer}",
+            case.input.display(),
+            case.expected
+        ))
+    }
+}